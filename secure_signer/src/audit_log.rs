@@ -0,0 +1,300 @@
+//! Append-only, hash-chained log of signed entries.
+//!
+//! Each entry signs the canonical serialization of its own fields together
+//! with the SHA-256 hash of the entry before it, so the log as a whole can
+//! be verified end to end: a missing entry, a reordered one, or a forged
+//! signature all break the chain rather than silently passing.
+//!
+//! The log itself is newline-delimited JSON (one [`LogEntry`] per line),
+//! so it can be appended to with a simple file write and inspected with
+//! ordinary line-oriented tools.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::crypto::{self, Curve, EncryptedKeyContainer};
+use crate::error::SignerError;
+
+/// One entry in an append-only, hash-chained log.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LogEntry {
+    /// Monotonically increasing position in the log, starting at 0.
+    pub sequence: u64,
+    /// SHA-256 hash (hex) of the previous entry's full serialized form,
+    /// or `None` for the first entry.
+    pub previous: Option<String>,
+    /// The caller-supplied content being attested to.
+    pub content: String,
+    /// Unix timestamp (seconds) the entry was signed at.
+    pub timestamp: u64,
+    /// Base58-encoded Ed25519 public key that signed this entry.
+    pub public_key: String,
+    /// Base58-encoded Ed25519 signature over this entry's signable fields.
+    pub signature: String,
+}
+
+/// The fields an entry's signature covers - everything but the signature
+/// itself. Kept separate from [`LogEntry`] so signing and verification
+/// serialize exactly the same bytes regardless of field order changes to
+/// the public struct.
+#[derive(Serialize)]
+struct SignablePayload<'a> {
+    sequence: u64,
+    previous: &'a Option<String>,
+    content: &'a str,
+    timestamp: u64,
+    public_key: &'a str,
+}
+
+/// Parse a newline-delimited log into its entries, in order.
+fn parse_log(log: &str) -> Result<Vec<LogEntry>, SignerError> {
+    log.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(SignerError::from))
+        .collect()
+}
+
+/// SHA-256 hash (hex) of an entry's full serialized form, including its
+/// own signature - this is what the *next* entry's `previous` must equal.
+fn entry_hash(entry: &LogEntry) -> Result<String, SignerError> {
+    let serialized = serde_json::to_vec(entry)?;
+    Ok(hex::encode(Sha256::digest(&serialized)))
+}
+
+/// Append a new signed entry to a hash-chained log, using the Ed25519 key
+/// in `container_json`.
+///
+/// `existing_log` is the log file's current contents (empty for a brand
+/// new log); the returned string is the full updated log, ready to be
+/// written back to the same file.
+pub fn append_entry(
+    existing_log: &str,
+    container_json: &str,
+    passphrase: &str,
+    content: &str,
+    timestamp: u64,
+) -> Result<String, SignerError> {
+    let entries = parse_log(existing_log)?;
+    let (sequence, previous) = match entries.last() {
+        Some(last) => (last.sequence + 1, Some(entry_hash(last)?)),
+        None => (0, None),
+    };
+
+    let container = EncryptedKeyContainer::from_json(container_json)?;
+    if container.curve != Curve::Ed25519 {
+        return Err(SignerError::SigningFailed(
+            "log-append only supports Ed25519 containers".to_string(),
+        ));
+    }
+
+    let mut secure_key = crypto::decrypt_container_key(&container, passphrase)?;
+    let signing_key = SigningKey::from_bytes(
+        secure_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| SignerError::InvalidKeyFormat(secure_key.len()))?,
+    );
+    secure_key.zeroize();
+    let public_key = bs58::encode(signing_key.verifying_key().as_bytes()).into_string();
+
+    let payload = SignablePayload {
+        sequence,
+        previous: &previous,
+        content,
+        timestamp,
+        public_key: &public_key,
+    };
+    let signing_bytes = serde_json::to_vec(&payload)?;
+    let signature = signing_key.sign(&signing_bytes);
+
+    let entry = LogEntry {
+        sequence,
+        previous,
+        content: content.to_string(),
+        timestamp,
+        public_key,
+        signature: bs58::encode(signature.to_bytes()).into_string(),
+    };
+
+    let mut updated = existing_log.to_string();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(&serde_json::to_string(&entry)?);
+    updated.push('\n');
+    Ok(updated)
+}
+
+/// Outcome of verifying a log: whether the whole chain is intact, and
+/// where it first broke if not.
+#[derive(Serialize)]
+pub struct VerifyResult {
+    pub valid: bool,
+    pub entry_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_invalid_sequence: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl VerifyResult {
+    fn broken(entries: &[LogEntry], sequence: u64, reason: &str) -> Self {
+        Self {
+            valid: false,
+            entry_count: entries.len(),
+            first_invalid_sequence: Some(sequence),
+            reason: Some(reason.to_string()),
+        }
+    }
+}
+
+/// Walk a hash-chained log, checking that every `previous` hash links
+/// correctly, every `sequence` increments by one from zero, and every
+/// signature validates against its entry's stated public key.
+pub fn verify_log(log: &str) -> Result<VerifyResult, SignerError> {
+    let entries = parse_log(log)?;
+    let mut previous_hash: Option<String> = None;
+    let mut expected_sequence = 0u64;
+
+    for entry in &entries {
+        if entry.sequence != expected_sequence {
+            return Ok(VerifyResult::broken(
+                &entries,
+                entry.sequence,
+                &format!(
+                    "expected sequence {}, found {}",
+                    expected_sequence, entry.sequence
+                ),
+            ));
+        }
+
+        if entry.previous != previous_hash {
+            return Ok(VerifyResult::broken(
+                &entries,
+                entry.sequence,
+                "previous hash does not match the prior entry",
+            ));
+        }
+
+        let payload = SignablePayload {
+            sequence: entry.sequence,
+            previous: &entry.previous,
+            content: &entry.content,
+            timestamp: entry.timestamp,
+            public_key: &entry.public_key,
+        };
+        let signing_bytes = serde_json::to_vec(&payload)?;
+
+        let public_key_bytes = bs58::decode(&entry.public_key).into_vec()?;
+        let verifying_key = match public_key_bytes
+            .as_slice()
+            .try_into()
+            .ok()
+            .and_then(|bytes: [u8; 32]| VerifyingKey::from_bytes(&bytes).ok())
+        {
+            Some(key) => key,
+            None => {
+                return Ok(VerifyResult::broken(
+                    &entries,
+                    entry.sequence,
+                    "public key is not a valid Ed25519 key",
+                ))
+            }
+        };
+
+        let signature_bytes = bs58::decode(&entry.signature).into_vec()?;
+        let signature = match Signature::from_slice(&signature_bytes) {
+            Ok(signature) => signature,
+            Err(_) => {
+                return Ok(VerifyResult::broken(
+                    &entries,
+                    entry.sequence,
+                    "signature is not well-formed",
+                ))
+            }
+        };
+
+        if verifying_key.verify(&signing_bytes, &signature).is_err() {
+            return Ok(VerifyResult::broken(
+                &entries,
+                entry.sequence,
+                "signature does not verify against the stated public key",
+            ));
+        }
+
+        previous_hash = Some(entry_hash(entry)?);
+        expected_sequence += 1;
+    }
+
+    Ok(VerifyResult {
+        valid: true,
+        entry_count: entries.len(),
+        first_invalid_sequence: None,
+        reason: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+    use rand::RngCore;
+
+    fn test_container() -> (String, &'static str) {
+        std::env::set_var("SIGNER_ALLOW_INSECURE_MEMORY", "1");
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let container_json = crypto::create_encrypted_key_container(&seed, "log_passphrase").unwrap();
+        (container_json, "log_passphrase")
+    }
+
+    #[test]
+    fn test_append_entry_chains_and_verifies() {
+        let (container_json, passphrase) = test_container();
+
+        let log = append_entry("", &container_json, passphrase, "genesis", 1000).unwrap();
+        let log = append_entry(&log, &container_json, passphrase, "second", 1001).unwrap();
+        let log = append_entry(&log, &container_json, passphrase, "third", 1002).unwrap();
+
+        let entries = parse_log(&log).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].sequence, 0);
+        assert!(entries[0].previous.is_none());
+        assert_eq!(entries[1].sequence, 1);
+        assert_eq!(entries[2].sequence, 2);
+
+        let result = verify_log(&log).unwrap();
+        assert!(result.valid);
+        assert_eq!(result.entry_count, 3);
+    }
+
+    #[test]
+    fn test_verify_log_detects_tampered_content() {
+        let (container_json, passphrase) = test_container();
+
+        let log = append_entry("", &container_json, passphrase, "genesis", 1000).unwrap();
+        let mut entries = parse_log(&log).unwrap();
+        entries[0].content = "tampered".to_string();
+        let tampered = serde_json::to_string(&entries[0]).unwrap() + "\n";
+
+        let result = verify_log(&tampered).unwrap();
+        assert!(!result.valid);
+        assert_eq!(result.first_invalid_sequence, Some(0));
+    }
+
+    #[test]
+    fn test_verify_log_detects_sequence_gap() {
+        let (container_json, passphrase) = test_container();
+
+        let log = append_entry("", &container_json, passphrase, "genesis", 1000).unwrap();
+        let log = append_entry(&log, &container_json, passphrase, "second", 1001).unwrap();
+
+        // Drop the first line, leaving a log that starts at sequence 1.
+        let gapped = log.lines().nth(1).unwrap().to_string() + "\n";
+
+        let result = verify_log(&gapped).unwrap();
+        assert!(!result.valid);
+        assert_eq!(result.first_invalid_sequence, Some(1));
+    }
+}
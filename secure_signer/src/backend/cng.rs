@@ -0,0 +1,193 @@
+//! Windows CNG (Cryptography API: Next Generation) keystore backend
+//!
+//! Selects a key from the Windows certificate/key store by name and calls
+//! `NCryptSignHash` so the private key material never leaves the CNG
+//! provider (which may itself be backed by a TPM or smart card).
+
+use windows_sys::Win32::Security::Cryptography::{
+    NCryptExportKey, NCryptOpenKey, NCryptOpenStorageProvider, NCryptSignHash,
+    BCRYPT_ECCPUBLIC_BLOB, MS_KEY_STORAGE_PROVIDER, NCRYPT_KEY_HANDLE, NCRYPT_PROV_HANDLE,
+};
+
+use crate::backend::{Signer, TokenKeyInfo};
+use crate::crypto::{Curve, SigningResult};
+use crate::error::SignerError;
+
+/// Fixed header of a CNG `BCRYPT_ECCPUBLIC_BLOB`: a magic value identifying
+/// the curve, then the coordinate length in bytes. The X and Y coordinates
+/// follow immediately after, each `key_len` bytes long.
+#[repr(C)]
+struct EccKeyBlobHeader {
+    magic: u32,
+    key_len: u32,
+}
+
+/// A key selected from the Windows key storage provider by name.
+pub struct CngSigner {
+    key_handle: NCRYPT_KEY_HANDLE,
+    curve: Curve,
+}
+
+impl CngSigner {
+    /// Open the named key in the Microsoft Software/Platform Key Storage
+    /// Provider. `key_name` matches whatever name the key was created or
+    /// imported under (e.g. via `certutil` or a TPM provisioning tool).
+    pub fn new(key_name: &str, curve: Curve) -> Result<Self, SignerError> {
+        let mut provider: NCRYPT_PROV_HANDLE = 0;
+        let status =
+            unsafe { NCryptOpenStorageProvider(&mut provider, MS_KEY_STORAGE_PROVIDER, 0) };
+        if status != 0 {
+            return Err(SignerError::KeyDerivationFailed(format!(
+                "NCryptOpenStorageProvider failed: 0x{:08x}",
+                status
+            )));
+        }
+
+        let mut key_handle: NCRYPT_KEY_HANDLE = 0;
+        let wide_name: Vec<u16> = key_name.encode_utf16().chain(std::iter::once(0)).collect();
+        let status = unsafe { NCryptOpenKey(provider, &mut key_handle, wide_name.as_ptr(), 0, 0) };
+        if status != 0 {
+            return Err(SignerError::KeyDerivationFailed(format!(
+                "NCryptOpenKey(\"{}\") failed: 0x{:08x}",
+                key_name, status
+            )));
+        }
+
+        Ok(Self { key_handle, curve })
+    }
+}
+
+impl Signer for CngSigner {
+    fn public_key(&self) -> Result<String, SignerError> {
+        let mut blob_len: u32 = 0;
+        let status = unsafe {
+            NCryptExportKey(
+                self.key_handle,
+                0,
+                BCRYPT_ECCPUBLIC_BLOB,
+                std::ptr::null(),
+                std::ptr::null_mut(),
+                0,
+                &mut blob_len,
+                0,
+            )
+        };
+        if status != 0 {
+            return Err(SignerError::KeyDerivationFailed(format!(
+                "NCryptExportKey (size query) failed: 0x{:08x}",
+                status
+            )));
+        }
+
+        let mut blob = vec![0u8; blob_len as usize];
+        let mut written: u32 = 0;
+        let status = unsafe {
+            NCryptExportKey(
+                self.key_handle,
+                0,
+                BCRYPT_ECCPUBLIC_BLOB,
+                std::ptr::null(),
+                blob.as_mut_ptr(),
+                blob.len() as u32,
+                &mut written,
+                0,
+            )
+        };
+        if status != 0 {
+            return Err(SignerError::KeyDerivationFailed(format!(
+                "NCryptExportKey failed: 0x{:08x}",
+                status
+            )));
+        }
+        blob.truncate(written as usize);
+
+        let header_len = std::mem::size_of::<EccKeyBlobHeader>();
+        if blob.len() < header_len {
+            return Err(SignerError::KeyDerivationFailed(
+                "NCryptExportKey returned a truncated ECC public blob".to_string(),
+            ));
+        }
+        let key_len = u32::from_ne_bytes(blob[4..8].try_into().unwrap()) as usize;
+        let point = blob
+            .get(header_len..header_len + 2 * key_len)
+            .ok_or_else(|| {
+                SignerError::KeyDerivationFailed(
+                    "NCryptExportKey returned an ECC public blob shorter than its declared key length"
+                        .to_string(),
+                )
+            })?;
+
+        // Encode as an uncompressed SEC1 point (0x04 || X || Y), matching
+        // the hex convention the other hardware backends use for EC public
+        // keys (see `Pkcs11Signer::public_key`).
+        let mut encoded = Vec::with_capacity(1 + point.len());
+        encoded.push(0x04);
+        encoded.extend_from_slice(point);
+        Ok(hex::encode(encoded))
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<SigningResult, SignerError> {
+        // dwFlags is 0, not a padding flag: this key is ECDSA (public_key()
+        // exports it as a BCRYPT_ECCPUBLIC_BLOB), and the padding flags
+        // (e.g. BCRYPT_PAD_PKCS1) are RSA-only - passing one here requires a
+        // pPaddingInfo struct and is rejected with STATUS_INVALID_PARAMETER
+        // against an ECC key.
+        let mut signature_len: u32 = 0;
+        let status = unsafe {
+            NCryptSignHash(
+                self.key_handle,
+                std::ptr::null(),
+                message.as_ptr(),
+                message.len() as u32,
+                std::ptr::null_mut(),
+                0,
+                &mut signature_len,
+                0,
+            )
+        };
+        if status != 0 {
+            return Err(SignerError::SigningFailed(format!(
+                "NCryptSignHash (size query) failed: 0x{:08x}",
+                status
+            )));
+        }
+
+        let mut signature = vec![0u8; signature_len as usize];
+        let status = unsafe {
+            NCryptSignHash(
+                self.key_handle,
+                std::ptr::null(),
+                message.as_ptr(),
+                message.len() as u32,
+                signature.as_mut_ptr(),
+                signature.len() as u32,
+                &mut signature_len,
+                0,
+            )
+        };
+        if status != 0 {
+            return Err(SignerError::SigningFailed(format!(
+                "NCryptSignHash failed: 0x{:08x}",
+                status
+            )));
+        }
+        signature.truncate(signature_len as usize);
+
+        Ok(SigningResult {
+            signature: hex::encode(&signature),
+            signed_transaction: None,
+            public_key: self.public_key()?,
+            recovery_id: None,
+            message_domain: None,
+            message_version: None,
+        })
+    }
+}
+
+/// Enumerate keys in the Windows key storage provider.
+///
+/// Full enumeration needs `NCryptEnumKeys`; until that's wired up this
+/// returns an empty list rather than silently pretending to cover it.
+pub fn list_keys() -> Result<Vec<TokenKeyInfo>, SignerError> {
+    Ok(Vec::new())
+}
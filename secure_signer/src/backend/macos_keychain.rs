@@ -0,0 +1,108 @@
+//! macOS Keychain / Secure Enclave backend
+//!
+//! Selects a key from the macOS Keychain by label and calls
+//! `SecKeyCreateSignature` via the Security framework, so the private key
+//! (which may be Secure Enclave-backed and non-exportable) never leaves
+//! the keychain service.
+
+use security_framework::item::{ItemClass, ItemSearchOptions, Reference, SearchResult};
+use security_framework::key::{Algorithm, SecKey};
+
+use crate::backend::{Signer, TokenKeyInfo};
+use crate::crypto::Curve;
+use crate::crypto::SigningResult;
+use crate::error::SignerError;
+
+/// A key selected from the macOS Keychain by label.
+pub struct MacKeychainSigner {
+    key: SecKey,
+    curve: Curve,
+}
+
+impl MacKeychainSigner {
+    /// Find the private key labeled `label` in the login keychain.
+    pub fn new(label: &str, curve: Curve) -> Result<Self, SignerError> {
+        let results = ItemSearchOptions::new()
+            .class(ItemClass::key())
+            .label(label)
+            .load_refs(true)
+            .search()
+            .map_err(|e| {
+                SignerError::KeyDerivationFailed(format!("Keychain search failed: {}", e))
+            })?;
+
+        let key = results
+            .into_iter()
+            .find_map(|item| match item {
+                SearchResult::Ref(Reference::Key(key)) => Some(key),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                SignerError::KeyDerivationFailed(format!(
+                    "no key labeled \"{}\" in the keychain",
+                    label
+                ))
+            })?;
+
+        Ok(Self { key, curve })
+    }
+
+    /// The `SecKeyAlgorithm` to sign with for this key's curve.
+    ///
+    /// Apple's Security framework (and the `security-framework` crate wrapping
+    /// it) doesn't expose an EdDSA `SecKeyAlgorithm` for `SecKeyCreateSignature`
+    /// - Ed25519 support on Apple platforms lives in CryptoKit, not Keychain
+    /// `SecKey`s - so a keychain-backed Ed25519 key can't actually be signed
+    /// with through this backend. Error out instead of silently signing with
+    /// the wrong (ECDSA) algorithm, which would produce a signature that
+    /// fails every verification.
+    fn algorithm(&self) -> Result<Algorithm, SignerError> {
+        match self.curve {
+            Curve::Ed25519 => Err(SignerError::SigningFailed(
+                "the macOS Keychain backend does not support Ed25519 keys; Apple's Security \
+                 framework only signs through SecKey with ECDSA/RSA algorithms"
+                    .to_string(),
+            )),
+            Curve::Secp256k1 => Ok(Algorithm::ECDSASignatureMessageX962SHA256),
+        }
+    }
+}
+
+impl Signer for MacKeychainSigner {
+    fn public_key(&self) -> Result<String, SignerError> {
+        let public_key = self.key.public_key().ok_or_else(|| {
+            SignerError::KeyDerivationFailed("keychain item has no public key".to_string())
+        })?;
+        let external_repr = public_key.external_representation().ok_or_else(|| {
+            SignerError::KeyDerivationFailed(
+                "could not export public key representation".to_string(),
+            )
+        })?;
+        Ok(hex::encode(external_repr.to_vec()))
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<SigningResult, SignerError> {
+        let signature = self
+            .key
+            .create_signature(self.algorithm()?, message)
+            .map_err(|e| SignerError::SigningFailed(format!("SecKeyCreateSignature failed: {}", e)))?;
+
+        Ok(SigningResult {
+            signature: hex::encode(&signature),
+            signed_transaction: None,
+            public_key: self.public_key()?,
+            recovery_id: None,
+            message_domain: None,
+            message_version: None,
+        })
+    }
+}
+
+/// Enumerate key items in the login keychain.
+///
+/// Full enumeration (mapping each item back to a curve) needs key-type
+/// introspection that isn't wired up yet, so this returns an empty list
+/// rather than guessing.
+pub fn list_keys() -> Result<Vec<TokenKeyInfo>, SignerError> {
+    Ok(Vec::new())
+}
@@ -0,0 +1,187 @@
+//! Pluggable signing backends
+//!
+//! ColdStar's default path decrypts a software container into locked
+//! memory and signs there (see [`crate::crypto::decrypt_and_sign`]). For
+//! keys that live in a hardware token or the platform keystore, the
+//! private key should never be imported into process memory at all - these
+//! backends instead ask the token/keystore to sign on our behalf and only
+//! ever see the public key and the resulting signature.
+//!
+//! Hardware/OS backends are each gated behind their own Cargo feature
+//! (`pkcs11`, `cng`, `macos-keychain`) since they pull in platform-specific
+//! dependencies that most builds of this crate don't need.
+//!
+//! [`sign_with_backend`] is the selection point: given a [`HardwareBackend`]
+//! and a key selector, it constructs the matching `Signer` and calls
+//! `.sign()`, so the CLI (`sign-hardware`) and the FFI boundary
+//! (`signer_sign_with_hardware_backend`) share one place that knows how to
+//! go from "which backend" to a signature, instead of each reaching into
+//! `backend::pkcs11`/`cng`/`macos_keychain` directly.
+
+mod software;
+
+#[cfg(feature = "pkcs11")]
+pub mod pkcs11;
+
+#[cfg(all(windows, feature = "cng"))]
+pub mod cng;
+
+#[cfg(all(target_os = "macos", feature = "macos-keychain"))]
+pub mod macos_keychain;
+
+pub use software::{SoftwareSigner, UnlockedSoftwareSigner};
+
+use crate::crypto::{Curve, SigningResult};
+use crate::error::SignerError;
+
+/// A backend capable of producing signatures for a single key, without
+/// necessarily ever exposing the private key material to this process.
+///
+/// `decrypt_and_sign` (the software container path) and each hardware/OS
+/// backend all implement this trait, so the FFI layer can select one at
+/// the boundary without the rest of the crate caring which it is.
+pub trait Signer {
+    /// The signer's public key, in the conventional encoding for its curve
+    /// (base58 for Ed25519, hex compressed point for secp256k1).
+    fn public_key(&self) -> Result<String, SignerError>;
+
+    /// Sign `message` and return a [`SigningResult`] in the same shape the
+    /// software backend produces.
+    fn sign(&self, message: &[u8]) -> Result<SigningResult, SignerError>;
+}
+
+/// Selects which hardware/OS backend [`sign_with_backend`] should construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardwareBackend {
+    Pkcs11,
+    Cng,
+    MacosKeychain,
+}
+
+impl std::str::FromStr for HardwareBackend {
+    type Err = SignerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pkcs11" => Ok(Self::Pkcs11),
+            "cng" => Ok(Self::Cng),
+            "keychain" | "macos-keychain" => Ok(Self::MacosKeychain),
+            other => Err(SignerError::KeyDerivationFailed(format!(
+                "unknown hardware backend \"{}\" (expected pkcs11, cng, or keychain)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Extra parameters a hardware backend may need to locate and unlock its key.
+///
+/// Only [`HardwareBackend::Pkcs11`] uses `module_path`/`pin`; the other
+/// backends select their key purely via `selector` (a CNG key name or a
+/// Keychain label).
+#[derive(Debug, Clone, Default)]
+pub struct HardwareBackendOptions {
+    /// Path to the vendor's PKCS#11 shared library (PKCS#11 only).
+    pub module_path: Option<String>,
+    /// Token PIN (PKCS#11 only).
+    pub pin: Option<String>,
+}
+
+/// Construct `backend`'s `Signer` for the key named by `selector` and sign
+/// `message` with it - the single point the CLI and FFI boundary both go
+/// through to reach `Pkcs11Signer`/`CngSigner`/`MacKeychainSigner`, so
+/// callers never need to reach into `backend::pkcs11`/`cng`/`macos_keychain`
+/// directly.
+///
+/// Returns a [`SignerError::KeyDerivationFailed`] if the requested backend
+/// wasn't compiled into this build (its Cargo feature, or its
+/// platform/feature combination, wasn't enabled).
+pub fn sign_with_backend(
+    backend: HardwareBackend,
+    selector: &str,
+    curve: Curve,
+    message: &[u8],
+    options: &HardwareBackendOptions,
+) -> Result<SigningResult, SignerError> {
+    match backend {
+        HardwareBackend::Pkcs11 => {
+            #[cfg(feature = "pkcs11")]
+            {
+                let module_path = options.module_path.as_deref().ok_or_else(|| {
+                    SignerError::KeyDerivationFailed(
+                        "the pkcs11 backend requires --module".to_string(),
+                    )
+                })?;
+                let pin = options.pin.as_deref().ok_or_else(|| {
+                    SignerError::KeyDerivationFailed(
+                        "the pkcs11 backend requires --pin".to_string(),
+                    )
+                })?;
+                pkcs11::Pkcs11Signer::new(module_path, pin, selector, curve)?.sign(message)
+            }
+            #[cfg(not(feature = "pkcs11"))]
+            {
+                Err(SignerError::KeyDerivationFailed(
+                    "this build was compiled without the pkcs11 backend".to_string(),
+                ))
+            }
+        }
+        HardwareBackend::Cng => {
+            #[cfg(all(windows, feature = "cng"))]
+            {
+                cng::CngSigner::new(selector, curve)?.sign(message)
+            }
+            #[cfg(not(all(windows, feature = "cng")))]
+            {
+                Err(SignerError::KeyDerivationFailed(
+                    "this build was compiled without the cng backend (windows only)".to_string(),
+                ))
+            }
+        }
+        HardwareBackend::MacosKeychain => {
+            #[cfg(all(target_os = "macos", feature = "macos-keychain"))]
+            {
+                macos_keychain::MacKeychainSigner::new(selector, curve)?.sign(message)
+            }
+            #[cfg(not(all(target_os = "macos", feature = "macos-keychain")))]
+            {
+                Err(SignerError::KeyDerivationFailed(
+                    "this build was compiled without the macos-keychain backend (macOS only)"
+                        .to_string(),
+                ))
+            }
+        }
+    }
+}
+
+/// Metadata describing a key available on a hardware token or OS keystore,
+/// without exposing the key material itself.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TokenKeyInfo {
+    /// Token/keystore-assigned label for the key.
+    pub label: String,
+    /// Token/keystore-assigned id, opaque, used to select the key again.
+    pub id: String,
+    /// The curve this key signs with.
+    pub curve: Curve,
+}
+
+/// Enumerate keys available across all compiled-in hardware/OS backends.
+///
+/// Returns an empty list (not an error) when no such backend is compiled
+/// into this build.
+pub fn list_token_keys() -> Result<Vec<TokenKeyInfo>, SignerError> {
+    #[allow(unused_mut)]
+    let mut keys = Vec::new();
+
+    #[cfg(feature = "pkcs11")]
+    keys.extend(pkcs11::list_keys()?);
+
+    #[cfg(all(windows, feature = "cng"))]
+    keys.extend(cng::list_keys()?);
+
+    #[cfg(all(target_os = "macos", feature = "macos-keychain"))]
+    keys.extend(macos_keychain::list_keys()?);
+
+    Ok(keys)
+}
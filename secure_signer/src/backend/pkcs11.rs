@@ -0,0 +1,202 @@
+//! PKCS#11 hardware token backend
+//!
+//! Finds a key object by label on a PKCS#11 token and asks the module to
+//! sign via `C_SignInit`/`C_Sign`, so the private key never leaves the
+//! token - this process only ever sees the public key and the signature.
+
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::Mechanism;
+use cryptoki::object::{Attribute, AttributeType, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, UserType};
+use cryptoki::slot::Slot;
+use cryptoki::types::AuthPin;
+
+use crate::backend::{Signer, TokenKeyInfo};
+use crate::crypto::{Curve, SigningResult};
+use crate::error::SignerError;
+
+/// Name of the environment variable pointing at the vendor's PKCS#11
+/// shared library, used by [`list_keys`] when no explicit path is given.
+const PKCS11_MODULE_ENV: &str = "COLDSTAR_PKCS11_MODULE";
+
+/// A key selected on a PKCS#11 token by label.
+pub struct Pkcs11Signer {
+    pkcs11: Pkcs11,
+    slot: Slot,
+    pin: String,
+    label: String,
+    curve: Curve,
+}
+
+impl Pkcs11Signer {
+    /// Open `module_path` (the vendor's PKCS#11 shared library) and select
+    /// the first token present in any slot.
+    pub fn new(
+        module_path: &str,
+        pin: &str,
+        label: &str,
+        curve: Curve,
+    ) -> Result<Self, SignerError> {
+        let pkcs11 = Pkcs11::new(module_path).map_err(|e| {
+            SignerError::KeyDerivationFailed(format!("failed to load PKCS#11 module: {}", e))
+        })?;
+        pkcs11
+            .initialize(CInitializeArgs::OsThreads)
+            .map_err(|e| SignerError::KeyDerivationFailed(format!("PKCS#11 init failed: {}", e)))?;
+
+        let slot = pkcs11
+            .get_slots_with_token()
+            .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                SignerError::KeyDerivationFailed("no PKCS#11 token present".to_string())
+            })?;
+
+        Ok(Self {
+            pkcs11,
+            slot,
+            pin: pin.to_string(),
+            label: label.to_string(),
+            curve,
+        })
+    }
+
+    fn open_session(&self) -> Result<Session, SignerError> {
+        let session = self.pkcs11.open_rw_session(self.slot).map_err(|e| {
+            SignerError::KeyDerivationFailed(format!("failed to open PKCS#11 session: {}", e))
+        })?;
+        session
+            .login(UserType::User, Some(&AuthPin::new(self.pin.clone())))
+            .map_err(|e| SignerError::KeyDerivationFailed(format!("PKCS#11 login failed: {}", e)))?;
+        Ok(session)
+    }
+
+    fn find_object(
+        &self,
+        session: &Session,
+        class: ObjectClass,
+    ) -> Result<ObjectHandle, SignerError> {
+        let template = vec![
+            Attribute::Class(class),
+            Attribute::Label(self.label.as_bytes().to_vec()),
+        ];
+        session
+            .find_objects(&template)
+            .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                SignerError::KeyDerivationFailed(format!(
+                    "no key labeled \"{}\" on token",
+                    self.label
+                ))
+            })
+    }
+
+    fn mechanism(&self) -> Mechanism {
+        match self.curve {
+            Curve::Ed25519 => Mechanism::Eddsa,
+            Curve::Secp256k1 => Mechanism::Ecdsa,
+        }
+    }
+}
+
+impl Signer for Pkcs11Signer {
+    fn public_key(&self) -> Result<String, SignerError> {
+        let session = self.open_session()?;
+        let handle = self.find_object(&session, ObjectClass::PUBLIC_KEY)?;
+
+        let attrs = session
+            .get_attributes(handle, &[AttributeType::EcPoint])
+            .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+
+        match attrs.into_iter().next() {
+            Some(Attribute::EcPoint(point)) => Ok(hex::encode(point)),
+            _ => Err(SignerError::KeyDerivationFailed(
+                "token did not return an EC point for the public key".to_string(),
+            )),
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<SigningResult, SignerError> {
+        let session = self.open_session()?;
+        let key = self.find_object(&session, ObjectClass::PRIVATE_KEY)?;
+
+        // C_SignInit + C_Sign: the private key never leaves the token.
+        let signature = session
+            .sign(&self.mechanism(), key, message)
+            .map_err(|e| SignerError::SigningFailed(format!("PKCS#11 sign failed: {}", e)))?;
+
+        Ok(SigningResult {
+            signature: hex::encode(&signature),
+            signed_transaction: None,
+            public_key: self.public_key()?,
+            recovery_id: None,
+            message_domain: None,
+            message_version: None,
+        })
+    }
+}
+
+/// Enumerate private key objects across every slot with a token present.
+///
+/// Reads the module path from [`PKCS11_MODULE_ENV`]; returns an empty list
+/// (not an error) if it isn't set, since listing is best-effort discovery
+/// rather than a required capability.
+pub fn list_keys() -> Result<Vec<TokenKeyInfo>, SignerError> {
+    let module_path = match std::env::var(PKCS11_MODULE_ENV) {
+        Ok(path) => path,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let pkcs11 = Pkcs11::new(&module_path).map_err(|e| {
+        SignerError::KeyDerivationFailed(format!("failed to load PKCS#11 module: {}", e))
+    })?;
+    pkcs11
+        .initialize(CInitializeArgs::OsThreads)
+        .map_err(|e| SignerError::KeyDerivationFailed(format!("PKCS#11 init failed: {}", e)))?;
+
+    let mut keys = Vec::new();
+    for slot in pkcs11
+        .get_slots_with_token()
+        .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?
+    {
+        let session = pkcs11
+            .open_ro_session(slot)
+            .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+
+        let template = vec![Attribute::Class(ObjectClass::PRIVATE_KEY)];
+        for handle in session
+            .find_objects(&template)
+            .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?
+        {
+            let attrs = session
+                .get_attributes(handle, &[AttributeType::Label, AttributeType::Id])
+                .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+
+            let mut label = String::new();
+            let mut id = String::new();
+            for attr in attrs {
+                match attr {
+                    Attribute::Label(bytes) => {
+                        label = String::from_utf8_lossy(&bytes).into_owned()
+                    }
+                    Attribute::Id(bytes) => id = hex::encode(bytes),
+                    _ => {}
+                }
+            }
+
+            // PKCS#11 doesn't expose curve choice without inspecting the EC
+            // parameters OID, so we report Ed25519 (this crate's default)
+            // unless the vendor's tooling labels the key otherwise.
+            keys.push(TokenKeyInfo {
+                label,
+                id,
+                curve: Curve::Ed25519,
+            });
+        }
+    }
+
+    Ok(keys)
+}
@@ -0,0 +1,140 @@
+//! The default backend: decrypt a software [`EncryptedKeyContainer`] into
+//! locked memory and sign there.
+
+use std::sync::Mutex;
+
+use crate::backend::Signer;
+use crate::crypto::{self, Curve, EncryptedKeyContainer, SigningResult};
+use crate::error::SignerError;
+use crate::secure_buffer::SecureBuffer;
+
+/// Signs by decrypting an [`EncryptedKeyContainer`] into a
+/// [`crate::secure_buffer::SecureBuffer`] for the duration of each call to
+/// [`Signer::sign`]. This is the backend every container-based API
+/// (`decrypt_and_sign`, the FFI `signer_sign_transaction`, etc.) already uses.
+pub struct SoftwareSigner {
+    container_json: String,
+    passphrase: String,
+}
+
+impl SoftwareSigner {
+    /// Wrap a container and passphrase as a [`Signer`].
+    pub fn new(container_json: impl Into<String>, passphrase: impl Into<String>) -> Self {
+        Self {
+            container_json: container_json.into(),
+            passphrase: passphrase.into(),
+        }
+    }
+}
+
+impl Signer for SoftwareSigner {
+    fn public_key(&self) -> Result<String, SignerError> {
+        let container = EncryptedKeyContainer::from_json(&self.container_json)?;
+        container.public_key.ok_or_else(|| {
+            SignerError::ContainerError("container has no embedded public key".to_string())
+        })
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<SigningResult, SignerError> {
+        crypto::decrypt_and_sign(&self.container_json, &self.passphrase, message)
+    }
+}
+
+/// A [`SoftwareSigner`] whose key has already been decrypted into locked
+/// memory, for callers that sign many messages and want to pay the
+/// decryption cost once instead of on every call - namely the `serve`
+/// daemon, which would otherwise re-derive the Argon2 key and decrypt the
+/// container on every incoming request.
+///
+/// The decrypted key lives in a [`SecureBuffer`] behind a [`Mutex`] for the
+/// life of this value; it is zeroized on drop exactly as [`SecureBuffer`]
+/// normally is.
+pub struct UnlockedSoftwareSigner {
+    secure_key: Mutex<SecureBuffer>,
+    curve: Curve,
+    public_key: String,
+}
+
+impl UnlockedSoftwareSigner {
+    /// Decrypt `container_json` with `passphrase` now, keeping the key in
+    /// locked memory until this value is dropped.
+    pub fn unlock(container_json: &str, passphrase: &str) -> Result<Self, SignerError> {
+        let container = EncryptedKeyContainer::from_json(container_json)?;
+        let secure_key = crypto::decrypt_container_key(&container, passphrase)?;
+        let public_key = match &container.public_key {
+            Some(public_key) => public_key.clone(),
+            None => crypto::derive_public_key(container.curve, secure_key.as_slice())?,
+        };
+
+        Ok(Self {
+            secure_key: Mutex::new(secure_key),
+            curve: container.curve,
+            public_key,
+        })
+    }
+}
+
+impl Signer for UnlockedSoftwareSigner {
+    fn public_key(&self) -> Result<String, SignerError> {
+        Ok(self.public_key.clone())
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<SigningResult, SignerError> {
+        let mut secure_key = self
+            .secure_key
+            .lock()
+            .map_err(|_| SignerError::SigningFailed("signing key lock poisoned".to_string()))?;
+        crypto::sign_with_secure_key(&mut secure_key, self.curve, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_software_signer_reports_embedded_public_key() {
+        std::env::set_var("SIGNER_ALLOW_INSECURE_MEMORY", "1");
+
+        let mut seed = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut seed);
+        let container_json = crypto::create_encrypted_key_container(&seed, "pw").unwrap();
+
+        let signer = SoftwareSigner::new(container_json, "pw");
+        let public_key = signer.public_key().unwrap();
+
+        let container = EncryptedKeyContainer::from_json(&signer.container_json).unwrap();
+        assert_eq!(Some(public_key), container.public_key);
+    }
+
+    #[test]
+    fn test_software_signer_signs_via_trait() {
+        std::env::set_var("SIGNER_ALLOW_INSECURE_MEMORY", "1");
+
+        let mut seed = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut seed);
+        let container_json = crypto::create_encrypted_key_container(&seed, "pw").unwrap();
+
+        let signer = SoftwareSigner::new(container_json, "pw");
+        let result = signer.sign(b"hello").unwrap();
+        assert!(!result.signature.is_empty());
+    }
+
+    #[test]
+    fn test_unlocked_software_signer_signs_without_redecrypting() {
+        std::env::set_var("SIGNER_ALLOW_INSECURE_MEMORY", "1");
+
+        let mut seed = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut seed);
+        let container_json = crypto::create_encrypted_key_container(&seed, "pw").unwrap();
+
+        let signer = UnlockedSoftwareSigner::unlock(&container_json, "pw").unwrap();
+        let public_key = signer.public_key().unwrap();
+
+        let first = signer.sign(b"hello").unwrap();
+        let second = signer.sign(b"world").unwrap();
+        assert_eq!(first.public_key, public_key);
+        assert_eq!(second.public_key, public_key);
+        assert_ne!(first.signature, second.signature);
+    }
+}
@@ -11,14 +11,18 @@
 //! to ensure memory is locked and zeroized.
 
 use aes_gcm::{
-    aead::{Aead, KeyInit},
+    aead::{Aead, KeyInit, Payload},
     Aes256Gcm, Nonce,
 };
+use aes_gcm_siv::{Aes256GcmSiv, Nonce as SivNonce};
 use argon2::{Argon2, Params, Version};
 use ed25519_dalek::{Signature, Signer, SigningKey};
+use k256::ecdsa::{RecoveryId, Signature as Secp256k1Signature, SigningKey as Secp256k1SigningKey};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 use rand::rngs::OsRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::error::SignerError;
 use crate::secure_buffer::{LockingMode, SecureBuffer};
@@ -29,7 +33,7 @@ use crate::secure_buffer::{LockingMode, SecureBuffer};
 const ENV_ALLOW_INSECURE: &str = "SIGNER_ALLOW_INSECURE_MEMORY";
 
 /// Get the appropriate locking mode based on environment
-fn get_locking_mode() -> LockingMode {
+pub(crate) fn get_locking_mode() -> LockingMode {
     match std::env::var(ENV_ALLOW_INSECURE) {
         Ok(val) if val == "1" || val.eq_ignore_ascii_case("true") => LockingMode::Permissive,
         _ => LockingMode::Strict,
@@ -48,6 +52,33 @@ const NONCE_SIZE: usize = 12; // 96 bits for AES-GCM
 const SALT_SIZE: usize = 32; // 256 bits for Argon2
 const ED25519_SEED_SIZE: usize = 32;
 const ED25519_KEYPAIR_SIZE: usize = 64;
+const SECP256K1_SECRET_SIZE: usize = 32;
+
+/// Fixed header for domain-separated off-chain messages: `0xff` can never
+/// begin a valid Solana transaction (which starts with a signature-count
+/// byte), so a signed message can never be replayed as a transaction.
+const MESSAGE_SIGNING_PREFIX: &[u8] = b"\xffsolana offchain";
+/// Version of the domain-separation scheme used by `sign_message`.
+const MESSAGE_SIGNING_VERSION: u8 = 1;
+
+/// Version 1 containers use plain AES-256-GCM with a random nonce.
+const CONTAINER_VERSION_GCM: u8 = 1;
+/// Version 2 containers use AES-256-GCM-SIV, which tolerates nonce reuse.
+const CONTAINER_VERSION_GCM_SIV: u8 = 2;
+
+/// The elliptic curve a container's key material is used with.
+///
+/// `Ed25519` is the Solana-native default; `Secp256k1` lets the same
+/// encrypted-container + `SecureBuffer` machinery produce ECDSA signatures
+/// for Ethereum/Bitcoin-style chains.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Curve {
+    #[default]
+    #[serde(rename = "ed25519")]
+    Ed25519,
+    #[serde(rename = "secp256k1")]
+    Secp256k1,
+}
 
 /// Encrypted key container format
 ///
@@ -61,13 +92,17 @@ const ED25519_KEYPAIR_SIZE: usize = 64;
 pub struct EncryptedKeyContainer {
     /// Version for future format changes
     pub version: u8,
+    /// The curve the contained key is used with (defaults to Ed25519 for
+    /// containers written before multi-curve support was added)
+    #[serde(default)]
+    pub curve: Curve,
     /// Salt for Argon2 key derivation (base64)
     pub salt: String,
     /// Nonce for AES-GCM (base64)
     pub nonce: String,
     /// Encrypted private key with auth tag (base64)
     pub ciphertext: String,
-    /// Public key for verification (base58, optional)
+    /// Public key for verification (base58 for Ed25519, hex for secp256k1; optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
 }
@@ -86,17 +121,44 @@ impl EncryptedKeyContainer {
     /// The private key is copied into a secure buffer for processing,
     /// and all intermediate values are zeroized.
     pub fn encrypt(private_key: &[u8], passphrase: &str) -> Result<Self, SignerError> {
-        // Validate key size
-        if private_key.len() != ED25519_SEED_SIZE && private_key.len() != ED25519_KEYPAIR_SIZE {
-            return Err(SignerError::InvalidKeyFormat(private_key.len()));
-        }
+        Self::encrypt_with_curve(private_key, passphrase, Curve::Ed25519)
+    }
 
-        // Use only the 32-byte seed (first half of keypair if 64 bytes)
-        let seed = &private_key[..ED25519_SEED_SIZE];
+    /// Create a new encrypted key container for a specific curve.
+    ///
+    /// # Arguments
+    /// * `private_key` - For `Ed25519`, the 32-byte seed or 64-byte keypair;
+    ///   for `Secp256k1`, the 32-byte secret scalar
+    /// * `passphrase` - The passphrase to encrypt with
+    /// * `curve` - Which curve this key material is used with
+    pub fn encrypt_with_curve(
+        private_key: &[u8],
+        passphrase: &str,
+        curve: Curve,
+    ) -> Result<Self, SignerError> {
+        // Validate key size per curve
+        let seed: &[u8] = match curve {
+            Curve::Ed25519 => {
+                if private_key.len() != ED25519_SEED_SIZE && private_key.len() != ED25519_KEYPAIR_SIZE {
+                    return Err(SignerError::InvalidKeyFormat(private_key.len()));
+                }
+                &private_key[..ED25519_SEED_SIZE]
+            }
+            Curve::Secp256k1 => {
+                if private_key.len() != SECP256K1_SECRET_SIZE {
+                    return Err(SignerError::InvalidKeyFormat(private_key.len()));
+                }
+                private_key
+            }
+        };
 
         // Copy to secure buffer for processing (uses env-based locking mode)
         let mut secure_key = SecureBuffer::from_slice_with_mode(seed, get_locking_mode())?;
 
+        // Get public key for verification; it also doubles as AAD below so a
+        // tampered public key field fails authentication on decrypt.
+        let public_key = derive_public_key(curve, secure_key.as_slice())?;
+
         // Generate random salt and nonce
         let mut salt = [0u8; SALT_SIZE];
         let mut nonce = [0u8; NONCE_SIZE];
@@ -106,28 +168,29 @@ impl EncryptedKeyContainer {
         // Derive encryption key from passphrase
         let mut derived_key = derive_key(passphrase.as_bytes(), &salt)?;
 
-        // Encrypt the private key
-        let cipher = Aes256Gcm::new_from_slice(derived_key.as_slice())
+        // Encrypt with AES-256-GCM-SIV (version 2): nonce-misuse-resistant,
+        // so a reused nonce only reveals plaintext equality rather than
+        // leaking the key or keystream.
+        let cipher = Aes256GcmSiv::new_from_slice(derived_key.as_slice())
             .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
 
         let ciphertext = cipher
-            .encrypt(Nonce::from_slice(&nonce), secure_key.as_slice())
+            .encrypt(
+                SivNonce::from_slice(&nonce),
+                Payload {
+                    msg: secure_key.as_slice(),
+                    aad: public_key.as_bytes(),
+                },
+            )
             .map_err(|_| SignerError::SigningFailed("Encryption failed".to_string()))?;
 
-        // Get public key for verification
-        let signing_key = SigningKey::from_bytes(
-            secure_key.as_slice().try_into().map_err(|_| {
-                SignerError::InvalidKeyFormat(secure_key.len())
-            })?,
-        );
-        let public_key = bs58::encode(signing_key.verifying_key().as_bytes()).into_string();
-
         // Zeroize sensitive data
         secure_key.zeroize();
         derived_key.zeroize();
 
         Ok(Self {
-            version: 1,
+            version: CONTAINER_VERSION_GCM_SIV,
+            curve,
             salt: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, salt),
             nonce: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, nonce),
             ciphertext: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, ciphertext),
@@ -135,9 +198,107 @@ impl EncryptedKeyContainer {
         })
     }
 
+    /// Create a new encrypted key container from a Solana CLI keypair JSON file.
+    ///
+    /// The file is a JSON array of 64 bytes: the 32-byte seed followed by the
+    /// 32-byte public key. The embedded public key is checked against the one
+    /// derived from the seed using a constant-time comparison, so a malformed
+    /// or tampered file can't be distinguished from a wrong-key file by timing.
+    pub fn encrypt_from_solana_keypair_json(json: &str, passphrase: &str) -> Result<Self, SignerError> {
+        let bytes: Vec<u8> = serde_json::from_str(json)
+            .map_err(|e| SignerError::ContainerError(format!("invalid keypair JSON: {}", e)))?;
+        Self::encrypt_from_keypair_bytes(&bytes, passphrase)
+    }
+
+    /// Create a new encrypted key container from a base58-encoded 64-byte
+    /// keypair string (e.g. a Phantom wallet export).
+    pub fn from_base58_keypair(keypair_b58: &str, passphrase: &str) -> Result<Self, SignerError> {
+        let bytes = bs58::decode(keypair_b58).into_vec()?;
+        Self::encrypt_from_keypair_bytes(&bytes, passphrase)
+    }
+
+    /// Shared validation + encryption path for the 64-byte keypair formats.
+    fn encrypt_from_keypair_bytes(bytes: &[u8], passphrase: &str) -> Result<Self, SignerError> {
+        if bytes.len() != ED25519_KEYPAIR_SIZE {
+            return Err(SignerError::InvalidKeyFormat(bytes.len()));
+        }
+
+        let seed = &bytes[..ED25519_SEED_SIZE];
+        let embedded_public_key = &bytes[ED25519_SEED_SIZE..];
+
+        let signing_key = SigningKey::from_bytes(
+            seed.try_into()
+                .map_err(|_| SignerError::InvalidKeyFormat(seed.len()))?,
+        );
+        let derived_public_key = signing_key.verifying_key().to_bytes();
+
+        if !ct_eq(&derived_public_key, embedded_public_key) {
+            return Err(SignerError::KeyMismatch);
+        }
+
+        Self::encrypt(seed, passphrase)
+    }
+
+    /// Decrypt a container and re-export its key as a Solana CLI keypair JSON
+    /// array (seed || public key), for round-tripping back to tools that
+    /// expect the on-disk keypair format.
+    ///
+    /// # Memory Lifecycle
+    /// The plaintext seed lives in a `SecureBuffer` throughout, and the
+    /// serialized JSON bytes are zeroized as soon as they're copied into the
+    /// `SecureBuffer` that's returned - they're never left sitting in an
+    /// ordinary unlocked allocation.
+    pub fn export_solana_keypair_json(
+        container_json: &str,
+        passphrase: &str,
+    ) -> Result<SecureBuffer, SignerError> {
+        let mut seed = decrypt_container_seed(container_json, passphrase)?;
+
+        let signing_key = SigningKey::from_bytes(
+            seed.as_slice()
+                .try_into()
+                .map_err(|_| SignerError::InvalidKeyFormat(seed.len()))?,
+        );
+
+        let mut keypair_bytes = [0u8; ED25519_KEYPAIR_SIZE];
+        keypair_bytes[..ED25519_SEED_SIZE].copy_from_slice(seed.as_slice());
+        keypair_bytes[ED25519_SEED_SIZE..].copy_from_slice(signing_key.verifying_key().as_bytes());
+        seed.zeroize();
+
+        let mut json_bytes = serde_json::to_vec(&keypair_bytes.to_vec())?;
+        let buffer = SecureBuffer::from_slice_with_mode(&json_bytes, get_locking_mode());
+        json_bytes.zeroize();
+        keypair_bytes.zeroize();
+        buffer
+    }
+
+    /// Create a new encrypted key container from a BIP39 mnemonic.
+    ///
+    /// # Arguments
+    /// * `mnemonic` - The BIP39 mnemonic phrase
+    /// * `bip39_passphrase` - Optional BIP39 passphrase (the "25th word")
+    /// * `path` - A hardened SLIP-0010 derivation path, e.g. `m/44'/501'/0'/0'`
+    /// * `encryption_passphrase` - The passphrase to encrypt the derived key with
+    ///
+    /// # Memory Lifecycle
+    /// The BIP39 seed and the derived Ed25519 seed are both held in
+    /// `SecureBuffer`s and zeroized as soon as the container is encrypted.
+    pub fn encrypt_from_mnemonic(
+        mnemonic: &str,
+        bip39_passphrase: &str,
+        path: &str,
+        encryption_passphrase: &str,
+    ) -> Result<Self, SignerError> {
+        let mut derived_seed =
+            crate::derivation::derive_ed25519_seed_from_mnemonic(mnemonic, bip39_passphrase, path)?;
+        let result = Self::encrypt(derived_seed.as_slice(), encryption_passphrase);
+        derived_seed.zeroize();
+        result
+    }
+
     /// Serialize the container to JSON
     pub fn to_json(&self) -> Result<String, SignerError> {
-        serde_json::to_string(self).map_err(|e| SignerError::SerializationError(e.to_string()))
+        Ok(serde_json::to_string(self)?)
     }
 
     /// Deserialize from JSON
@@ -149,13 +310,22 @@ impl EncryptedKeyContainer {
 /// Result of a signing operation
 #[derive(Serialize, Deserialize)]
 pub struct SigningResult {
-    /// The signature (base58 encoded)
+    /// The signature (base58 for Ed25519, hex for secp256k1)
     pub signature: String,
     /// The signed transaction (base64 encoded, if transaction was provided)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signed_transaction: Option<String>,
-    /// The public key that signed (base58 encoded)
+    /// The public key that signed (base58 for Ed25519, hex for secp256k1)
     pub public_key: String,
+    /// The ECDSA recovery id (secp256k1 only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recovery_id: Option<u8>,
+    /// The application domain bound into the preimage (sign_message only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_domain: Option<String>,
+    /// The domain-separation scheme version used (sign_message only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_version: Option<u8>,
 }
 
 /// Decrypt a key container and sign a transaction
@@ -183,9 +353,30 @@ pub fn decrypt_and_sign(
     passphrase: &str,
     transaction_bytes: &[u8],
 ) -> Result<SigningResult, SignerError> {
-    // Parse the container
     let container = EncryptedKeyContainer::from_json(container_json)?;
+    let mut secure_key = decrypt_container_key(&container, passphrase)?;
+
+    // Create signing key from secure buffer
+    // MEMORY LIFECYCLE: The signing key is created from our secure buffer
+    // and will be zeroized when dropped (ed25519-dalek supports zeroize)
+    let result = sign_with_secure_key(&mut secure_key, container.curve, transaction_bytes);
 
+    // Explicit zeroization (also happens on drop)
+    secure_key.zeroize();
+
+    result
+}
+
+/// Decrypt a container's key material into a `SecureBuffer`, without signing anything.
+///
+/// Shared by [`decrypt_and_sign`] and the key-export/re-derivation helpers so
+/// the version-dispatch and AAD handling only live in one place. Also used
+/// by [`crate::backend::UnlockedSoftwareSigner`] to decrypt once at startup
+/// instead of on every signing call.
+pub(crate) fn decrypt_container_key(
+    container: &EncryptedKeyContainer,
+    passphrase: &str,
+) -> Result<SecureBuffer, SignerError> {
     // Decode base64 fields
     let salt = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &container.salt)?;
     let nonce = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &container.nonce)?;
@@ -194,31 +385,303 @@ pub fn decrypt_and_sign(
     // Derive decryption key
     let mut derived_key = derive_key(passphrase.as_bytes(), &salt)?;
 
-    // Decrypt the private key into secure buffer
-    let cipher = Aes256Gcm::new_from_slice(derived_key.as_slice())
-        .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
-
-    let plaintext = cipher
-        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
-        .map_err(|_| SignerError::DecryptionFailed)?;
+    // Dispatch on the container version so old version-1 GCM containers
+    // still decrypt alongside version-2 GCM-SIV ones.
+    let plaintext = match container.version {
+        CONTAINER_VERSION_GCM => {
+            let cipher = Aes256Gcm::new_from_slice(derived_key.as_slice())
+                .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+                .map_err(|_| SignerError::DecryptionFailed)?
+        }
+        CONTAINER_VERSION_GCM_SIV => {
+            let cipher = Aes256GcmSiv::new_from_slice(derived_key.as_slice())
+                .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+            let aad = container.public_key.as_deref().unwrap_or("").as_bytes();
+            cipher
+                .decrypt(
+                    SivNonce::from_slice(&nonce),
+                    Payload {
+                        msg: ciphertext.as_slice(),
+                        aad,
+                    },
+                )
+                .map_err(|_| SignerError::DecryptionFailed)?
+        }
+        other => {
+            derived_key.zeroize();
+            return Err(SignerError::ContainerError(format!(
+                "unsupported container version: {}",
+                other
+            )));
+        }
+    };
 
     // Immediately move to secure buffer and zeroize intermediate
-    let mut secure_key = SecureBuffer::from_slice_with_mode(&plaintext, get_locking_mode())?;
+    let secure_key = SecureBuffer::from_slice_with_mode(&plaintext, get_locking_mode())?;
 
     // Zeroize the derived key and plaintext copy
     derived_key.zeroize();
     // Note: plaintext is owned by cipher, can't zeroize it directly
     // But we've copied to secure buffer immediately
 
-    // Create signing key from secure buffer
-    // MEMORY LIFECYCLE: The signing key is created from our secure buffer
-    // and will be zeroized when dropped (ed25519-dalek supports zeroize)
-    let result = sign_with_secure_key(&mut secure_key, transaction_bytes);
+    Ok(secure_key)
+}
 
-    // Explicit zeroization (also happens on drop)
+/// Decrypt a container's Ed25519 seed from its JSON form.
+///
+/// Convenience wrapper around [`decrypt_container_key`] for callers (like the
+/// keypair export path) that only have the serialized container on hand.
+fn decrypt_container_seed(container_json: &str, passphrase: &str) -> Result<SecureBuffer, SignerError> {
+    let container = EncryptedKeyContainer::from_json(container_json)?;
+    decrypt_container_key(&container, passphrase)
+}
+
+/// Build the exact byte preimage that [`sign_message`] signs: the fixed
+/// [`MESSAGE_SIGNING_PREFIX`], a version byte, the length-prefixed
+/// application domain, then the message itself. Because the preimage always
+/// starts with `0xff`, it can never be mistaken for (or replayed as) a
+/// Solana transaction, which always starts with a signature-count byte.
+fn build_message_preimage(domain: &str, message: &[u8]) -> Result<Vec<u8>, SignerError> {
+    if domain.len() > u8::MAX as usize {
+        return Err(SignerError::SigningFailed(
+            "message domain must be at most 255 bytes".to_string(),
+        ));
+    }
+
+    let mut preimage = Vec::with_capacity(
+        MESSAGE_SIGNING_PREFIX.len() + 1 + 1 + domain.len() + message.len(),
+    );
+    preimage.extend_from_slice(MESSAGE_SIGNING_PREFIX);
+    preimage.push(MESSAGE_SIGNING_VERSION);
+    preimage.push(domain.len() as u8);
+    preimage.extend_from_slice(domain.as_bytes());
+    preimage.extend_from_slice(message);
+    Ok(preimage)
+}
+
+/// Sign an off-chain message with a domain-separation prefix.
+///
+/// Unlike [`decrypt_and_sign`], which signs raw transaction bytes, this
+/// prepends [`MESSAGE_SIGNING_PREFIX`] and an application `domain` string to
+/// the message before signing, so a signature over human-readable text can
+/// never be replayed as an on-chain transaction signature. The domain and
+/// scheme version are returned in the `SigningResult` so a verifier can
+/// reconstruct the exact signed bytes.
+///
+/// # Arguments
+/// * `container_json` - JSON-serialized EncryptedKeyContainer (Ed25519 only)
+/// * `passphrase` - The passphrase for decryption
+/// * `domain` - An application-specific domain string (at most 255 bytes)
+/// * `message` - The message to sign
+pub fn sign_message(
+    container_json: &str,
+    passphrase: &str,
+    domain: &str,
+    message: &[u8],
+) -> Result<SigningResult, SignerError> {
+    let container = EncryptedKeyContainer::from_json(container_json)?;
+    if container.curve != Curve::Ed25519 {
+        return Err(SignerError::SigningFailed(
+            "sign_message only supports Ed25519 containers".to_string(),
+        ));
+    }
+
+    let mut secure_key = decrypt_container_key(&container, passphrase)?;
+    let preimage = build_message_preimage(domain, message)?;
+
+    let signing_key = SigningKey::from_bytes(
+        secure_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| SignerError::InvalidKeyFormat(secure_key.len()))?,
+    );
+    let signature = signing_key.sign(&preimage);
     secure_key.zeroize();
 
-    result
+    Ok(SigningResult {
+        signature: bs58::encode(signature.to_bytes()).into_string(),
+        signed_transaction: None,
+        public_key: bs58::encode(signing_key.verifying_key().as_bytes()).into_string(),
+        recovery_id: None,
+        message_domain: Some(domain.to_string()),
+        message_version: Some(MESSAGE_SIGNING_VERSION),
+    })
+}
+
+/// Verify a signature produced by [`sign_message`].
+///
+/// Reconstructs the exact domain-separated preimage and checks it against
+/// the given Ed25519 public key.
+pub fn verify_message(
+    public_key_b58: &str,
+    domain: &str,
+    message: &[u8],
+    signature_b58: &str,
+) -> Result<bool, SignerError> {
+    use ed25519_dalek::Verifier;
+
+    let preimage = build_message_preimage(domain, message)?;
+
+    let public_key_bytes = bs58::decode(public_key_b58).into_vec()?;
+    let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(
+        public_key_bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| SignerError::InvalidKeyFormat(public_key_bytes.len()))?,
+    )
+    .map_err(|e| SignerError::SigningFailed(e.to_string()))?;
+
+    let signature_bytes = bs58::decode(signature_b58).into_vec()?;
+    let signature = Signature::from_slice(&signature_bytes)
+        .map_err(|e| SignerError::SigningFailed(e.to_string()))?;
+
+    Ok(verifying_key.verify(&preimage, &signature).is_ok())
+}
+
+/// Fixed JOSE header for the tokens produced by [`sign_jws`]: EdDSA is the
+/// only algorithm this function ever signs with, so the header never varies.
+const JWS_HEADER: &str = r#"{"alg":"EdDSA","typ":"JWT"}"#;
+
+/// Sign a JSON payload as a compact EdDSA JWS, using the container's
+/// Ed25519 key to mint auth tokens rather than Solana transactions.
+///
+/// Produces the standard RFC 7515 compact form - `header.payload.signature`,
+/// all three segments base64url-encoded without padding - so the result is
+/// interoperable with any other EdDSA JWT library. This is deliberately
+/// *not* routed through [`sign_message`]'s domain-separated preimage: JWS
+/// consumers expect to verify the literal `header.payload` ASCII bytes, not
+/// a Solana-specific wrapper around them.
+///
+/// # Arguments
+/// * `container_json` - JSON-serialized EncryptedKeyContainer (Ed25519 only)
+/// * `passphrase` - The passphrase for decryption
+/// * `payload_json` - The JWS payload, already serialized to JSON
+pub fn sign_jws(
+    container_json: &str,
+    passphrase: &str,
+    payload_json: &str,
+) -> Result<String, SignerError> {
+    let container = EncryptedKeyContainer::from_json(container_json)?;
+    if container.curve != Curve::Ed25519 {
+        return Err(SignerError::SigningFailed(
+            "sign_jws only supports Ed25519 containers".to_string(),
+        ));
+    }
+
+    let mut secure_key = decrypt_container_key(&container, passphrase)?;
+    let signing_key = SigningKey::from_bytes(
+        secure_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| SignerError::InvalidKeyFormat(secure_key.len()))?,
+    );
+    secure_key.zeroize();
+
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let header_b64 = base64::Engine::encode(&engine, JWS_HEADER);
+    let payload_b64 = base64::Engine::encode(&engine, payload_json);
+    let signing_input = format!("{}.{}", header_b64, payload_b64);
+
+    let signature = signing_key.sign(signing_input.as_bytes());
+    let signature_b64 = base64::Engine::encode(&engine, signature.to_bytes());
+
+    Ok(format!("{}.{}", signing_input, signature_b64))
+}
+
+/// Maximum message length (in bytes) accepted by [`sign_offchain_message`],
+/// matching Solana's off-chain signing specification.
+const OFFCHAIN_MESSAGE_MAX_LEN: usize = 1232;
+
+/// The 16-byte domain prefix for Solana's off-chain signing standard.
+/// `0xff` can never begin a valid Solana transaction (which starts with a
+/// signature-count byte), so a signature over this preimage can never be
+/// replayed as a transaction signature.
+const OFFCHAIN_DOMAIN_PREFIX: &[u8; 16] = b"\xffsolana offchain";
+/// Version of the off-chain signing domain implemented here.
+const OFFCHAIN_VERSION: u8 = 0;
+/// Message format byte: restricted ASCII (printable range plus `\n`/`\r`).
+const OFFCHAIN_FORMAT_RESTRICTED_ASCII: u8 = 0;
+/// Message format byte: arbitrary UTF-8.
+const OFFCHAIN_FORMAT_UTF8: u8 = 1;
+
+/// Whether `message` fits the "restricted ASCII" format: printable ASCII
+/// plus `\n`/`\r`, nothing else.
+fn is_restricted_ascii(message: &[u8]) -> bool {
+    message
+        .iter()
+        .all(|&b| matches!(b, 0x0a | 0x0d | 0x20..=0x7e))
+}
+
+/// Build the preimage for Solana's off-chain signing domain: the 16-byte
+/// domain prefix, a version byte, a message-format byte (chosen from the
+/// input), a 2-byte little-endian length, then the message itself.
+fn build_offchain_preimage(message: &[u8]) -> Result<Vec<u8>, SignerError> {
+    if message.len() > OFFCHAIN_MESSAGE_MAX_LEN {
+        return Err(SignerError::SigningFailed(format!(
+            "off-chain message exceeds the maximum length of {} bytes",
+            OFFCHAIN_MESSAGE_MAX_LEN
+        )));
+    }
+
+    let format = if is_restricted_ascii(message) {
+        OFFCHAIN_FORMAT_RESTRICTED_ASCII
+    } else if std::str::from_utf8(message).is_ok() {
+        OFFCHAIN_FORMAT_UTF8
+    } else {
+        return Err(SignerError::SigningFailed(
+            "off-chain message is not valid UTF-8".to_string(),
+        ));
+    };
+
+    let mut preimage =
+        Vec::with_capacity(OFFCHAIN_DOMAIN_PREFIX.len() + 1 + 1 + 2 + message.len());
+    preimage.extend_from_slice(OFFCHAIN_DOMAIN_PREFIX);
+    preimage.push(OFFCHAIN_VERSION);
+    preimage.push(format);
+    preimage.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    preimage.extend_from_slice(message);
+    Ok(preimage)
+}
+
+/// Sign a message using Solana's official off-chain signing domain.
+///
+/// Distinct from [`sign_message`], which uses a ColdStar-specific
+/// domain-separated preimage: this follows the wire format from Solana's
+/// off-chain message signing standard, so the resulting signature verifies
+/// against any wallet or tool implementing the same spec. Like
+/// [`sign_transaction`] (and unlike the container-based signing
+/// functions), this signs directly from a raw private key already in
+/// secure memory.
+///
+/// # Arguments
+/// * `private_key` - The 32-byte Ed25519 seed
+/// * `message` - The message to sign (at most [`OFFCHAIN_MESSAGE_MAX_LEN`] bytes)
+pub fn sign_offchain_message(
+    private_key: &[u8],
+    message: &[u8],
+) -> Result<SigningResult, SignerError> {
+    let mut secure_key = SecureBuffer::from_slice_with_mode(private_key, get_locking_mode())?;
+    let preimage = build_offchain_preimage(message)?;
+
+    let signing_key = SigningKey::from_bytes(
+        secure_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| SignerError::InvalidKeyFormat(secure_key.len()))?,
+    );
+    let signature = signing_key.sign(&preimage);
+    secure_key.zeroize();
+
+    Ok(SigningResult {
+        signature: bs58::encode(signature.to_bytes()).into_string(),
+        signed_transaction: None,
+        public_key: bs58::encode(signing_key.verifying_key().as_bytes()).into_string(),
+        recovery_id: None,
+        message_domain: Some("solana-offchain".to_string()),
+        message_version: Some(OFFCHAIN_VERSION),
+    })
 }
 
 /// Sign a transaction with a key in a secure buffer
@@ -226,7 +689,22 @@ pub fn decrypt_and_sign(
 /// # Memory Lifecycle
 /// The secure buffer is borrowed mutably and its contents are used
 /// to create a signing key. The signing key itself supports zeroization.
-fn sign_with_secure_key(
+/// Crate-visible so [`crate::backend::UnlockedSoftwareSigner`] can sign
+/// against an already-decrypted buffer without going through
+/// [`decrypt_and_sign`]'s per-call decryption.
+pub(crate) fn sign_with_secure_key(
+    secure_key: &mut SecureBuffer,
+    curve: Curve,
+    transaction_bytes: &[u8],
+) -> Result<SigningResult, SignerError> {
+    match curve {
+        Curve::Ed25519 => sign_with_ed25519_key(secure_key, transaction_bytes),
+        Curve::Secp256k1 => sign_with_secp256k1_key(secure_key, transaction_bytes),
+    }
+}
+
+/// Sign with an Ed25519 key held in a secure buffer (Solana-compatible).
+fn sign_with_ed25519_key(
     secure_key: &mut SecureBuffer,
     transaction_bytes: &[u8],
 ) -> Result<SigningResult, SignerError> {
@@ -246,33 +724,281 @@ fn sign_with_secure_key(
     let public_key = signing_key.verifying_key();
     let public_key_b58 = bs58::encode(public_key.as_bytes()).into_string();
 
-    // Sign the transaction message
-    let signature: Signature = signing_key.sign(transaction_bytes);
+    // If this looks like a real Solana wire transaction (compact-u16
+    // signature count + signature slots + message), sign the message
+    // portion and splice the signature into this signer's slot, leaving
+    // every other slot untouched. Otherwise fall back to signing the raw
+    // bytes directly and don't fabricate a transaction blob around it.
+    let (signature, signed_transaction) = match parse_solana_transaction(transaction_bytes) {
+        Some(parsed) => {
+            let signer_index = parsed
+                .required_signer_keys()
+                .iter()
+                .position(|key| key.as_slice() == public_key.as_bytes())
+                .ok_or_else(|| {
+                    SignerError::InvalidTransaction(
+                        "signer public key is not among the transaction's required signers"
+                            .to_string(),
+                    )
+                })?;
 
-    // For Solana transactions, we need to embed the signature
-    // The transaction format is: signatures_count + signatures + message
-    // We'll return just the signature; the caller can construct the full tx
-    let signature_b58 = bs58::encode(signature.to_bytes()).into_string();
+            let signature = signing_key.sign(parsed.message);
 
-    // Build signed transaction if this looks like a Solana transaction message
-    let signed_transaction = if transaction_bytes.len() >= 3 {
-        // Simple signed transaction: 1 signature count + signature + message
-        let mut signed_tx = Vec::with_capacity(1 + 64 + transaction_bytes.len());
-        signed_tx.push(1u8); // One signature
-        signed_tx.extend_from_slice(&signature.to_bytes());
-        signed_tx.extend_from_slice(transaction_bytes);
-        Some(base64::Engine::encode(
-            &base64::engine::general_purpose::STANDARD,
-            &signed_tx,
-        ))
-    } else {
-        None
+            let mut signed_tx = transaction_bytes.to_vec();
+            let slot_start = parsed.signatures_start + signer_index * SOLANA_SIGNATURE_SIZE;
+            signed_tx[slot_start..slot_start + SOLANA_SIGNATURE_SIZE]
+                .copy_from_slice(&signature.to_bytes());
+
+            let encoded = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &signed_tx);
+            (signature, Some(encoded))
+        }
+        None => (signing_key.sign(transaction_bytes), None),
     };
 
+    let signature_b58 = bs58::encode(signature.to_bytes()).into_string();
+
     Ok(SigningResult {
         signature: signature_b58,
         signed_transaction,
         public_key: public_key_b58,
+        recovery_id: None,
+        message_domain: None,
+        message_version: None,
+    })
+}
+
+/// A Solana wire transaction, decomposed enough to locate and splice a
+/// signer's slot, and to render a human-readable preview, without needing
+/// the full instruction/account-metadata model.
+struct ParsedSolanaTransaction<'a> {
+    /// Byte offset of the first signature slot within the original transaction.
+    signatures_start: usize,
+    /// Number of required signers (copied from the message header).
+    num_required_signatures: usize,
+    /// The static account keys, in order (the first `num_required_signatures` are signers).
+    account_keys: Vec<[u8; 32]>,
+    /// The blockhash the transaction is valid against.
+    recent_blockhash: [u8; 32],
+    /// Instructions, in order, referencing `account_keys` by index.
+    instructions: Vec<ParsedInstruction>,
+    /// The serialized message - everything the signatures are computed over.
+    message: &'a [u8],
+}
+
+impl<'a> ParsedSolanaTransaction<'a> {
+    fn required_signer_keys(&self) -> &[[u8; 32]] {
+        &self.account_keys[..self.num_required_signatures]
+    }
+
+    /// The first required signer, which the Solana runtime always treats
+    /// as the transaction's fee payer.
+    fn fee_payer(&self) -> Option<&[u8; 32]> {
+        self.account_keys.first()
+    }
+}
+
+/// A single instruction within a parsed transaction, referencing its
+/// program and accounts by index into the transaction's account key list.
+struct ParsedInstruction {
+    /// Index into `account_keys` of the program this instruction invokes.
+    program_id_index: u8,
+    /// Indices into `account_keys` of the accounts this instruction touches.
+    account_indices: Vec<u8>,
+    /// Length of the instruction's opaque data, in bytes.
+    data_len: usize,
+}
+
+/// 64-byte Ed25519 signature slot size used by the Solana wire format.
+const SOLANA_SIGNATURE_SIZE: usize = 64;
+/// 32-byte public key size used by the Solana wire format.
+const SOLANA_PUBKEY_SIZE: usize = 32;
+
+/// Best-effort parse of `bytes` as a Solana wire transaction: compact-u16
+/// signature count, that many 64-byte signature slots, then the message
+/// (header + account keys + blockhash + instructions).
+///
+/// Returns `None` - rather than an error - when `bytes` doesn't structurally
+/// look like a transaction at all, so arbitrary messages can still be signed
+/// directly by the caller.
+fn parse_solana_transaction(bytes: &[u8]) -> Option<ParsedSolanaTransaction<'_>> {
+    let (num_signatures, sig_count_len) = decode_compact_u16(bytes)?;
+    let num_signatures = num_signatures as usize;
+
+    let signatures_start = sig_count_len;
+    let signatures_end = signatures_start.checked_add(num_signatures.checked_mul(SOLANA_SIGNATURE_SIZE)?)?;
+    let message = bytes.get(signatures_end..)?;
+
+    // Message header: num_required_signatures, num_readonly_signed, num_readonly_unsigned
+    let header = message.get(..3)?;
+    let num_required_signatures = header[0] as usize;
+    if num_required_signatures != num_signatures {
+        return None;
+    }
+
+    let (num_account_keys, account_count_len) = decode_compact_u16(&message[3..])?;
+    let num_account_keys = num_account_keys as usize;
+    if num_account_keys < num_required_signatures {
+        return None;
+    }
+
+    let accounts_start = 3 + account_count_len;
+    let accounts_end = accounts_start.checked_add(num_account_keys.checked_mul(SOLANA_PUBKEY_SIZE)?)?;
+    let account_key_bytes = message.get(accounts_start..accounts_end)?;
+
+    let account_keys = account_key_bytes
+        .chunks_exact(SOLANA_PUBKEY_SIZE)
+        .map(|chunk| chunk.try_into().expect("chunk is exactly 32 bytes"))
+        .collect();
+
+    let blockhash_start = accounts_end;
+    let blockhash_end = blockhash_start.checked_add(32)?;
+    let recent_blockhash: [u8; 32] = message.get(blockhash_start..blockhash_end)?.try_into().ok()?;
+
+    let (num_instructions, ix_count_len) = decode_compact_u16(message.get(blockhash_end..)?)?;
+    let mut offset = blockhash_end + ix_count_len;
+    let mut instructions = Vec::with_capacity(num_instructions as usize);
+    for _ in 0..num_instructions {
+        let program_id_index = *message.get(offset)?;
+        offset += 1;
+
+        let (num_accounts, accounts_len_len) = decode_compact_u16(message.get(offset..)?)?;
+        offset += accounts_len_len;
+        let account_indices = message.get(offset..offset.checked_add(num_accounts as usize)?)?.to_vec();
+        offset += num_accounts as usize;
+
+        let (data_len, data_len_len) = decode_compact_u16(message.get(offset..)?)?;
+        offset += data_len_len;
+        let data_len = data_len as usize;
+        message.get(offset..offset.checked_add(data_len)?)?;
+        offset += data_len;
+
+        instructions.push(ParsedInstruction {
+            program_id_index,
+            account_indices,
+            data_len,
+        });
+    }
+
+    Some(ParsedSolanaTransaction {
+        signatures_start,
+        num_required_signatures,
+        account_keys,
+        recent_blockhash,
+        instructions,
+        message,
+    })
+}
+
+/// Decode a Solana "compact-u16" (shortvec) length prefix.
+///
+/// Returns `(value, bytes_consumed)`, or `None` if the prefix is truncated
+/// or overflows a `u16`.
+fn decode_compact_u16(bytes: &[u8]) -> Option<(u16, usize)> {
+    let mut value: u32 = 0;
+    let mut shift = 0;
+    for (i, &byte) in bytes.iter().enumerate().take(3) {
+        value |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return if value <= u16::MAX as u32 {
+                Some((value as u16, i + 1))
+            } else {
+                None
+            };
+        }
+        shift += 7;
+    }
+    None
+}
+
+/// Encode a Solana "compact-u16" (shortvec) length prefix.
+fn encode_compact_u16(mut value: u16) -> Vec<u8> {
+    let mut out = Vec::with_capacity(2);
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+    out
+}
+
+/// Normalize a secp256k1 signature to low-S form (BIP-62 / EIP-2
+/// malleability fix): if `s > n/2`, replace it with `n - s` and flip the
+/// recovery id's parity bit so recovery still yields the same public key.
+fn normalize_low_s(
+    signature: Secp256k1Signature,
+    recovery_id: RecoveryId,
+) -> (Secp256k1Signature, RecoveryId) {
+    match signature.normalize_s() {
+        Some(normalized) => {
+            let flipped = RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced());
+            (normalized, flipped)
+        }
+        None => (signature, recovery_id),
+    }
+}
+
+/// Recover a compressed secp256k1 public key (hex-encoded) from a message
+/// and a recoverable signature, without needing the private key.
+///
+/// Useful for verifying a `signer_sign_secp256k1_recoverable` signature
+/// against an untrusted claimed public key.
+pub fn recover_pubkey(
+    message_bytes: &[u8],
+    signature_hex: &str,
+    recovery_id: u8,
+) -> Result<String, SignerError> {
+    let sig_bytes = hex::decode(signature_hex)
+        .map_err(|e| SignerError::SigningFailed(format!("invalid signature hex: {}", e)))?;
+    let signature = Secp256k1Signature::from_slice(&sig_bytes)
+        .map_err(|e| SignerError::SigningFailed(format!("invalid signature: {}", e)))?;
+    let recovery_id = RecoveryId::from_byte(recovery_id)
+        .ok_or_else(|| SignerError::SigningFailed("invalid recovery id".to_string()))?;
+
+    let verifying_key =
+        k256::ecdsa::VerifyingKey::recover_from_msg(message_bytes, &signature, recovery_id)
+            .map_err(|e| SignerError::SigningFailed(format!("public key recovery failed: {}", e)))?;
+
+    Ok(hex::encode(verifying_key.to_encoded_point(true).as_bytes()))
+}
+
+/// Sign with a secp256k1 key held in a secure buffer, producing a 64-byte
+/// compact signature plus a recovery id (RFC-6979 deterministic nonce, no
+/// RNG involved).
+fn sign_with_secp256k1_key(
+    secure_key: &mut SecureBuffer,
+    message_bytes: &[u8],
+) -> Result<SigningResult, SignerError> {
+    if secure_key.len() != SECP256K1_SECRET_SIZE {
+        return Err(SignerError::InvalidKeyFormat(secure_key.len()));
+    }
+
+    let signing_key = Secp256k1SigningKey::from_slice(secure_key.as_slice())
+        .map_err(|e| SignerError::KeyDerivationFailed(format!("invalid secp256k1 secret: {}", e)))?;
+
+    let (signature, recovery_id): (Secp256k1Signature, RecoveryId) = signing_key
+        .sign_recoverable(message_bytes)
+        .map_err(|e| SignerError::SigningFailed(e.to_string()))?;
+    let (signature, recovery_id) = normalize_low_s(signature, recovery_id);
+
+    let public_key_hex = hex::encode(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    let signature_hex = hex::encode(signature.to_bytes());
+
+    Ok(SigningResult {
+        signature: signature_hex,
+        signed_transaction: None,
+        public_key: public_key_hex,
+        recovery_id: Some(recovery_id.to_byte()),
+        message_domain: None,
+        message_version: None,
     })
 }
 
@@ -293,7 +1019,7 @@ pub fn sign_transaction(
     let mut secure_key = SecureBuffer::from_slice_with_mode(private_key, get_locking_mode())?;
 
     // Sign
-    let result = sign_with_secure_key(&mut secure_key, transaction_bytes);
+    let result = sign_with_secure_key(&mut secure_key, Curve::Ed25519, transaction_bytes);
 
     // Zeroize
     secure_key.zeroize();
@@ -301,6 +1027,110 @@ pub fn sign_transaction(
     result
 }
 
+/// A single instruction in a [`DecodedTransaction`], with its program and
+/// accounts resolved to base58 keys rather than left as raw indices.
+#[derive(Serialize)]
+pub struct DecodedInstruction {
+    /// Base58-encoded program this instruction invokes.
+    pub program_id: String,
+    /// Base58-encoded accounts this instruction touches, in order.
+    pub accounts: Vec<String>,
+    /// Length of the instruction's opaque data, in bytes (the data itself
+    /// isn't decoded - instruction layouts are program-specific).
+    pub data_len: usize,
+}
+
+/// A human-readable breakdown of a Solana transaction, for previewing what
+/// a signature would actually authorize before it's requested.
+#[derive(Serialize)]
+pub struct DecodedTransaction {
+    /// Base58-encoded fee payer (the transaction's first required signer).
+    pub fee_payer: String,
+    /// Base58-encoded recent blockhash the transaction is valid against.
+    pub recent_blockhash: String,
+    /// All static account keys, in order, base58-encoded.
+    pub account_keys: Vec<String>,
+    /// Number of account keys that must sign this transaction.
+    pub num_required_signatures: usize,
+    /// The transaction's instructions, in order.
+    pub instructions: Vec<DecodedInstruction>,
+}
+
+/// Decode `transaction_bytes` into a human-readable [`DecodedTransaction`]
+/// without signing anything, so a caller can inspect what a signature would
+/// authorize before requesting one.
+pub fn decode_transaction(transaction_bytes: &[u8]) -> Result<DecodedTransaction, SignerError> {
+    let parsed = parse_solana_transaction(transaction_bytes).ok_or_else(|| {
+        SignerError::InvalidTransaction("not a valid Solana wire transaction".to_string())
+    })?;
+
+    let fee_payer = parsed
+        .fee_payer()
+        .ok_or_else(|| SignerError::InvalidTransaction("transaction has no accounts".to_string()))?;
+
+    let instructions = parsed
+        .instructions
+        .iter()
+        .map(|instruction| -> Result<DecodedInstruction, SignerError> {
+            let program_id = parsed
+                .account_keys
+                .get(instruction.program_id_index as usize)
+                .ok_or_else(|| {
+                    SignerError::InvalidTransaction(
+                        "instruction references an out-of-range program id".to_string(),
+                    )
+                })?;
+            let accounts = instruction
+                .account_indices
+                .iter()
+                .map(|&index| {
+                    parsed
+                        .account_keys
+                        .get(index as usize)
+                        .map(|key| bs58::encode(key).into_string())
+                        .ok_or_else(|| {
+                            SignerError::InvalidTransaction(
+                                "instruction references an out-of-range account".to_string(),
+                            )
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(DecodedInstruction {
+                program_id: bs58::encode(program_id).into_string(),
+                accounts,
+                data_len: instruction.data_len,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(DecodedTransaction {
+        fee_payer: bs58::encode(fee_payer).into_string(),
+        recent_blockhash: bs58::encode(parsed.recent_blockhash).into_string(),
+        account_keys: parsed
+            .account_keys
+            .iter()
+            .map(|key| bs58::encode(key).into_string())
+            .collect(),
+        num_required_signatures: parsed.num_required_signatures,
+        instructions,
+    })
+}
+
+/// A confirmation hash over a transaction's decoded contents: a SHA-256
+/// digest of its canonical JSON [`DecodedTransaction`] form, hex-encoded.
+///
+/// Used to gate `Sign --require-preview`: the caller must echo this hash
+/// back (having seen the decoded transaction first) before the key is
+/// used, so a swapped or tampered transaction can't be blind-signed.
+pub fn confirmation_hash(transaction_bytes: &[u8]) -> Result<String, SignerError> {
+    use sha2::{Digest, Sha256};
+
+    let decoded = decode_transaction(transaction_bytes)?;
+    let canonical = serde_json::to_vec(&decoded)?;
+    Ok(hex::encode(Sha256::digest(&canonical)))
+}
+
 /// Create an encrypted key container from a private key
 ///
 /// Convenience function for creating containers.
@@ -312,6 +1142,43 @@ pub fn create_encrypted_key_container(
     container.to_json()
 }
 
+/// Compare two byte slices in constant time (no early exit on first mismatch).
+///
+/// Used to check an embedded public key against a derived one without
+/// letting a malformed or tampered keypair file be distinguished by timing.
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+/// Derive the public key string for a curve's secret, in that curve's
+/// conventional encoding (base58 for Ed25519, hex compressed point for secp256k1).
+pub(crate) fn derive_public_key(curve: Curve, secret: &[u8]) -> Result<String, SignerError> {
+    match curve {
+        Curve::Ed25519 => {
+            let signing_key = SigningKey::from_bytes(
+                secret
+                    .try_into()
+                    .map_err(|_| SignerError::InvalidKeyFormat(secret.len()))?,
+            );
+            Ok(bs58::encode(signing_key.verifying_key().as_bytes()).into_string())
+        }
+        Curve::Secp256k1 => {
+            let signing_key = Secp256k1SigningKey::from_slice(secret)
+                .map_err(|e| SignerError::KeyDerivationFailed(format!("invalid secp256k1 secret: {}", e)))?;
+            Ok(hex::encode(
+                signing_key.verifying_key().to_encoded_point(true).as_bytes(),
+            ))
+        }
+    }
+}
+
 /// Derive an encryption key from a passphrase using Argon2id
 ///
 /// # Memory Lifecycle
@@ -389,10 +1256,382 @@ mod tests {
         assert!(matches!(result, Err(SignerError::DecryptionFailed)));
     }
 
+    #[test]
+    fn test_legacy_v1_gcm_container_still_decrypts() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let passphrase = "legacy_passphrase";
+
+        // Hand-build a version-1 container the way the old encrypt() did,
+        // to prove decrypt_and_sign still honours the legacy format.
+        let mut salt = [0u8; SALT_SIZE];
+        let mut nonce = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let derived_key = derive_key(passphrase.as_bytes(), &salt).unwrap();
+        let cipher = Aes256Gcm::new_from_slice(derived_key.as_slice()).unwrap();
+        let ciphertext = cipher.encrypt(Nonce::from_slice(&nonce), seed.as_slice()).unwrap();
+
+        let container = EncryptedKeyContainer {
+            version: CONTAINER_VERSION_GCM,
+            curve: Curve::Ed25519,
+            salt: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, salt),
+            nonce: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, nonce),
+            ciphertext: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, ciphertext),
+            public_key: None,
+        };
+        let json = container.to_json().unwrap();
+
+        let result = decrypt_and_sign(&json, passphrase, b"test message").unwrap();
+        let signing_key = SigningKey::from_bytes(&seed);
+        assert_eq!(
+            result.public_key,
+            bs58::encode(signing_key.verifying_key().as_bytes()).into_string()
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_container_roundtrip_and_recovery() {
+        enable_permissive_mode();
+
+        let mut secret = [0u8; SECP256K1_SECRET_SIZE];
+        OsRng.fill_bytes(&mut secret);
+        let passphrase = "secp_test_passphrase";
+
+        let container =
+            EncryptedKeyContainer::encrypt_with_curve(&secret, passphrase, Curve::Secp256k1).unwrap();
+        assert_eq!(container.curve, Curve::Secp256k1);
+        let json = container.to_json().unwrap();
+
+        let message = b"ethereum-style message";
+        let result = decrypt_and_sign(&json, passphrase, message).unwrap();
+        assert!(result.recovery_id.is_some());
+
+        let signing_key = Secp256k1SigningKey::from_slice(&secret).unwrap();
+        let expected_public_key = hex::encode(
+            signing_key.verifying_key().to_encoded_point(true).as_bytes(),
+        );
+        assert_eq!(result.public_key, expected_public_key);
+
+        let recovered = recover_pubkey(message, &result.signature, result.recovery_id.unwrap())
+            .unwrap();
+        assert_eq!(recovered, expected_public_key);
+    }
+
+    #[test]
+    fn test_encrypt_from_solana_keypair_json_roundtrip() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; ED25519_SEED_SIZE];
+        OsRng.fill_bytes(&mut seed);
+        let signing_key = SigningKey::from_bytes(&seed);
+
+        let mut keypair_bytes = Vec::with_capacity(ED25519_KEYPAIR_SIZE);
+        keypair_bytes.extend_from_slice(&seed);
+        keypair_bytes.extend_from_slice(signing_key.verifying_key().as_bytes());
+        let json = serde_json::to_string(&keypair_bytes).unwrap();
+        let passphrase = "keypair_json_passphrase";
+
+        let container = EncryptedKeyContainer::encrypt_from_solana_keypair_json(&json, passphrase).unwrap();
+        let container_json = container.to_json().unwrap();
+
+        let result = decrypt_and_sign(&container_json, passphrase, b"test").unwrap();
+        assert_eq!(
+            result.public_key,
+            bs58::encode(signing_key.verifying_key().as_bytes()).into_string()
+        );
+    }
+
+    #[test]
+    fn test_encrypt_from_solana_keypair_json_rejects_mismatched_public_key() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; ED25519_SEED_SIZE];
+        OsRng.fill_bytes(&mut seed);
+
+        let mut keypair_bytes = Vec::with_capacity(ED25519_KEYPAIR_SIZE);
+        keypair_bytes.extend_from_slice(&seed);
+        keypair_bytes.extend_from_slice(&[0xAAu8; ED25519_SEED_SIZE]); // wrong public key
+        let json = serde_json::to_string(&keypair_bytes).unwrap();
+
+        let result = EncryptedKeyContainer::encrypt_from_solana_keypair_json(&json, "pass");
+        assert!(matches!(result, Err(SignerError::KeyMismatch)));
+    }
+
+    #[test]
+    fn test_from_base58_keypair_and_export_roundtrip() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; ED25519_SEED_SIZE];
+        OsRng.fill_bytes(&mut seed);
+        let signing_key = SigningKey::from_bytes(&seed);
+
+        let mut keypair_bytes = Vec::with_capacity(ED25519_KEYPAIR_SIZE);
+        keypair_bytes.extend_from_slice(&seed);
+        keypair_bytes.extend_from_slice(signing_key.verifying_key().as_bytes());
+        let keypair_b58 = bs58::encode(&keypair_bytes).into_string();
+        let passphrase = "base58_keypair_passphrase";
+
+        let container = EncryptedKeyContainer::from_base58_keypair(&keypair_b58, passphrase).unwrap();
+        let container_json = container.to_json().unwrap();
+
+        let exported = EncryptedKeyContainer::export_solana_keypair_json(&container_json, passphrase).unwrap();
+        let exported_bytes: Vec<u8> = serde_json::from_slice(exported.as_slice()).unwrap();
+        assert_eq!(exported_bytes, keypair_bytes);
+    }
+
+    #[test]
+    fn test_sign_message_and_verify_message_roundtrip() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let container = EncryptedKeyContainer::encrypt(&seed, "msg_passphrase").unwrap();
+        let container_json = container.to_json().unwrap();
+
+        let result = sign_message(
+            &container_json,
+            "msg_passphrase",
+            "coldstar.auth",
+            b"please log me in",
+        )
+        .unwrap();
+        assert_eq!(result.message_domain.as_deref(), Some("coldstar.auth"));
+        assert_eq!(result.message_version, Some(MESSAGE_SIGNING_VERSION));
+
+        let verified = verify_message(
+            &result.public_key,
+            "coldstar.auth",
+            b"please log me in",
+            &result.signature,
+        )
+        .unwrap();
+        assert!(verified);
+    }
+
+    #[test]
+    fn test_sign_message_rejects_wrong_domain_on_verify() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let container = EncryptedKeyContainer::encrypt(&seed, "msg_passphrase").unwrap();
+        let container_json = container.to_json().unwrap();
+
+        let result = sign_message(&container_json, "msg_passphrase", "domain-a", b"hello").unwrap();
+        let verified = verify_message(&result.public_key, "domain-b", b"hello", &result.signature).unwrap();
+        assert!(!verified);
+    }
+
+    #[test]
+    fn test_sign_jws_produces_a_verifiable_compact_token() {
+        use ed25519_dalek::Verifier;
+
+        enable_permissive_mode();
+
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let container = EncryptedKeyContainer::encrypt(&seed, "jws_passphrase").unwrap();
+        let container_json = container.to_json().unwrap();
+
+        let payload = r#"{"sub":"alice"}"#;
+        let token = sign_jws(&container_json, "jws_passphrase", payload).unwrap();
+
+        let segments: Vec<&str> = token.split('.').collect();
+        assert_eq!(segments.len(), 3);
+
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        let header_bytes = base64::Engine::decode(&engine, segments[0]).unwrap();
+        assert_eq!(header_bytes, JWS_HEADER.as_bytes());
+        let payload_bytes = base64::Engine::decode(&engine, segments[1]).unwrap();
+        assert_eq!(payload_bytes, payload.as_bytes());
+
+        let verifying_key = SigningKey::from_bytes(&seed).verifying_key();
+        let signing_input = format!("{}.{}", segments[0], segments[1]);
+        let signature_bytes = base64::Engine::decode(&engine, segments[2]).unwrap();
+        let signature = Signature::from_slice(&signature_bytes).unwrap();
+        assert!(verifying_key.verify(signing_input.as_bytes(), &signature).is_ok());
+    }
+
+    #[test]
+    fn test_sign_offchain_message_verifies_against_official_preimage() {
+        use ed25519_dalek::Verifier;
+
+        enable_permissive_mode();
+
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+
+        let message = b"please confirm withdrawal";
+        let result = sign_offchain_message(&seed, message).unwrap();
+        assert_eq!(result.message_version, Some(OFFCHAIN_VERSION));
+
+        let verifying_key = SigningKey::from_bytes(&seed).verifying_key();
+        let preimage = build_offchain_preimage(message).unwrap();
+        assert!(preimage.starts_with(OFFCHAIN_DOMAIN_PREFIX));
+        assert_eq!(preimage[16], OFFCHAIN_VERSION);
+        assert_eq!(preimage[17], OFFCHAIN_FORMAT_RESTRICTED_ASCII);
+
+        let signature_bytes = bs58::decode(&result.signature).into_vec().unwrap();
+        let signature = Signature::from_slice(&signature_bytes).unwrap();
+        assert!(verifying_key.verify(&preimage, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_sign_offchain_message_rejects_oversized_message() {
+        enable_permissive_mode();
+
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+
+        let message = vec![b'a'; OFFCHAIN_MESSAGE_MAX_LEN + 1];
+        assert!(sign_offchain_message(&seed, &message).is_err());
+    }
+
+    /// Build a minimal valid Solana wire transaction with two required
+    /// signers, zeroed signature slots, and no instructions.
+    fn build_test_transaction(signer_keys: &[[u8; 32]]) -> Vec<u8> {
+        let num_signers = signer_keys.len() as u16;
+
+        let mut tx = Vec::new();
+        tx.extend_from_slice(&encode_compact_u16(num_signers)); // signature count
+        tx.extend(std::iter::repeat(0u8).take(signer_keys.len() * SOLANA_SIGNATURE_SIZE)); // empty slots
+
+        // Message: header + account keys + blockhash + (empty) instructions
+        tx.push(num_signers as u8); // num_required_signatures
+        tx.push(0); // num_readonly_signed_accounts
+        tx.push(0); // num_readonly_unsigned_accounts
+        tx.extend_from_slice(&encode_compact_u16(num_signers)); // account key count
+        for key in signer_keys {
+            tx.extend_from_slice(key);
+        }
+        tx.extend_from_slice(&[0u8; 32]); // recent blockhash
+        tx.extend_from_slice(&encode_compact_u16(0)); // no instructions
+
+        tx
+    }
+
+    #[test]
+    fn test_multi_signer_transaction_splices_correct_slot() {
+        enable_permissive_mode();
+
+        let mut seed_a = [0u8; 32];
+        let mut seed_b = [0u8; 32];
+        OsRng.fill_bytes(&mut seed_a);
+        OsRng.fill_bytes(&mut seed_b);
+
+        let signing_key_a = SigningKey::from_bytes(&seed_a);
+        let signing_key_b = SigningKey::from_bytes(&seed_b);
+        let pubkey_a = *signing_key_a.verifying_key().as_bytes();
+        let pubkey_b = *signing_key_b.verifying_key().as_bytes();
+
+        let transaction = build_test_transaction(&[pubkey_a, pubkey_b]);
+
+        // Sign as the *second* required signer.
+        let result = sign_transaction(&seed_b, &transaction).unwrap();
+        let signed_tx_b64 = result.signed_transaction.unwrap();
+        let signed_tx = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &signed_tx_b64).unwrap();
+
+        // Signer B's slot (index 1) should hold a valid signature over the message...
+        let message = &transaction[1 + 2 * SOLANA_SIGNATURE_SIZE..];
+        let slot_b = 1 + SOLANA_SIGNATURE_SIZE;
+        let sig_b = Signature::from_slice(&signed_tx[slot_b..slot_b + SOLANA_SIGNATURE_SIZE]).unwrap();
+        use ed25519_dalek::Verifier;
+        assert!(signing_key_b.verifying_key().verify(message, &sig_b).is_ok());
+
+        // ...while signer A's slot stays untouched (all zeros).
+        assert!(signed_tx[1..1 + SOLANA_SIGNATURE_SIZE].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_decode_transaction_reports_fee_payer_and_blockhash() {
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let pubkey = *SigningKey::from_bytes(&seed).verifying_key().as_bytes();
+
+        let transaction = build_test_transaction(&[pubkey]);
+        let decoded = decode_transaction(&transaction).unwrap();
+
+        assert_eq!(decoded.fee_payer, bs58::encode(pubkey).into_string());
+        assert_eq!(decoded.recent_blockhash, bs58::encode([0u8; 32]).into_string());
+        assert_eq!(decoded.num_required_signatures, 1);
+        assert!(decoded.instructions.is_empty());
+    }
+
+    #[test]
+    fn test_decode_transaction_resolves_instruction_accounts() {
+        let mut payer_seed = [0u8; 32];
+        OsRng.fill_bytes(&mut payer_seed);
+        let payer = *SigningKey::from_bytes(&payer_seed).verifying_key().as_bytes();
+        let program_id = [7u8; 32];
+
+        let mut tx = Vec::new();
+        tx.extend_from_slice(&encode_compact_u16(1));
+        tx.extend(std::iter::repeat(0u8).take(SOLANA_SIGNATURE_SIZE));
+
+        tx.push(1); // num_required_signatures
+        tx.push(0);
+        tx.push(0);
+        tx.extend_from_slice(&encode_compact_u16(2)); // account keys: payer, program
+        tx.extend_from_slice(&payer);
+        tx.extend_from_slice(&program_id);
+        tx.extend_from_slice(&[9u8; 32]); // recent blockhash
+        tx.extend_from_slice(&encode_compact_u16(1)); // one instruction
+        tx.push(1); // program_id_index -> program_id
+        tx.extend_from_slice(&encode_compact_u16(1)); // one account
+        tx.push(0); // account index -> payer
+        tx.extend_from_slice(&encode_compact_u16(3)); // data length
+        tx.extend_from_slice(&[1, 2, 3]);
+
+        let decoded = decode_transaction(&tx).unwrap();
+        assert_eq!(decoded.recent_blockhash, bs58::encode([9u8; 32]).into_string());
+        assert_eq!(decoded.instructions.len(), 1);
+        assert_eq!(decoded.instructions[0].program_id, bs58::encode(program_id).into_string());
+        assert_eq!(decoded.instructions[0].accounts, vec![bs58::encode(payer).into_string()]);
+        assert_eq!(decoded.instructions[0].data_len, 3);
+    }
+
+    #[test]
+    fn test_confirmation_hash_changes_with_transaction_contents() {
+        let mut pubkey_a = [0u8; 32];
+        let mut pubkey_b = [0u8; 32];
+        OsRng.fill_bytes(&mut pubkey_a);
+        OsRng.fill_bytes(&mut pubkey_b);
+
+        let tx_a = build_test_transaction(&[pubkey_a]);
+        let tx_b = build_test_transaction(&[pubkey_b]);
+
+        let hash_a = confirmation_hash(&tx_a).unwrap();
+        let hash_a_again = confirmation_hash(&tx_a).unwrap();
+        let hash_b = confirmation_hash(&tx_b).unwrap();
+
+        assert_eq!(hash_a, hash_a_again);
+        assert_ne!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn test_unrecognized_signer_is_rejected() {
+        enable_permissive_mode();
+
+        let mut seed_a = [0u8; 32];
+        let mut seed_outsider = [0u8; 32];
+        OsRng.fill_bytes(&mut seed_a);
+        OsRng.fill_bytes(&mut seed_outsider);
+
+        let pubkey_a = *SigningKey::from_bytes(&seed_a).verifying_key().as_bytes();
+        let transaction = build_test_transaction(&[pubkey_a]);
+
+        let result = sign_transaction(&seed_outsider, &transaction);
+        assert!(matches!(result, Err(SignerError::InvalidTransaction(_))));
+    }
+
     #[test]
     fn test_signature_verification() {
         enable_permissive_mode();
-        
+
         use ed25519_dalek::Verifier;
 
         let mut seed = [0u8; 32];
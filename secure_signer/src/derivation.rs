@@ -0,0 +1,178 @@
+//! BIP39 mnemonic and SLIP-0010 hierarchical key derivation
+//!
+//! This module turns a BIP39 mnemonic phrase into an Ed25519 signing seed,
+//! following the hardened derivation paths used by Solana wallets
+//! (e.g. `m/44'/501'/0'/0'`).
+//!
+//! # Security Model
+//!
+//! Every intermediate value - the PBKDF2 seed and each SLIP-0010
+//! key/chain-code pair - is copied into a `SecureBuffer` immediately after
+//! it is computed and zeroized as soon as it is no longer needed.
+
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2;
+use sha2::Sha512;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+
+use crate::crypto::get_locking_mode;
+use crate::error::SignerError;
+use crate::secure_buffer::SecureBuffer;
+
+/// Iteration count mandated by the BIP39 spec for mnemonic -> seed.
+const PBKDF2_ROUNDS: u32 = 2048;
+/// BIP39 seeds are always 64 bytes.
+const BIP39_SEED_SIZE: usize = 64;
+/// SLIP-0010 chain codes are always 32 bytes.
+const CHAIN_CODE_SIZE: usize = 32;
+/// Ed25519 seeds are 32 bytes.
+const ED25519_SEED_SIZE: usize = 32;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Derive the 64-byte BIP39 seed from a mnemonic phrase and optional passphrase.
+///
+/// The mnemonic and passphrase are NFKD-normalized before being fed into
+/// PBKDF2-HMAC-SHA512 with the standard `"mnemonic" || passphrase` salt and
+/// 2048 iterations, matching every other BIP39 wallet.
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<SecureBuffer, SignerError> {
+    let normalized_mnemonic: String = mnemonic.nfkd().collect();
+    let normalized_passphrase: String = passphrase.nfkd().collect();
+    let salt = format!("mnemonic{}", normalized_passphrase);
+
+    let mut seed = SecureBuffer::with_mode(BIP39_SEED_SIZE, get_locking_mode())?;
+    pbkdf2::<HmacSha512>(
+        normalized_mnemonic.as_bytes(),
+        salt.as_bytes(),
+        PBKDF2_ROUNDS,
+        seed.as_mut_slice(),
+    )
+    .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+
+    Ok(seed)
+}
+
+/// Parse a derivation path such as `m/44'/501'/0'/0'` into hardened child indices.
+///
+/// SLIP-0010 ed25519 derivation only supports hardened indices, so every
+/// segment must carry the `'` (or `h`) hardened marker.
+fn parse_derivation_path(path: &str) -> Result<Vec<u32>, SignerError> {
+    let rest = path
+        .strip_prefix("m/")
+        .ok_or_else(|| SignerError::KeyDerivationFailed(format!("path must start with \"m/\": {}", path)))?;
+
+    rest.split('/')
+        .map(|segment| {
+            let hardened = segment.ends_with('\'') || segment.ends_with('h');
+            if !hardened {
+                return Err(SignerError::KeyDerivationFailed(format!(
+                    "ed25519 SLIP-0010 derivation only supports hardened indices, got \"{}\"",
+                    segment
+                )));
+            }
+
+            let index: u32 = segment
+                .trim_end_matches(['\'', 'h'])
+                .parse()
+                .map_err(|_| SignerError::KeyDerivationFailed(format!("invalid path segment: {}", segment)))?;
+
+            Ok(index | 0x8000_0000)
+        })
+        .collect()
+}
+
+/// Derive an Ed25519 seed at `path` from a BIP39 (or other) master seed, per SLIP-0010.
+///
+/// `I = HMAC-SHA512(key = "ed25519 seed", data = seed)` produces the master
+/// key/chain-code pair, then each hardened index folds in
+/// `I = HMAC-SHA512(key = chain_code, data = 0x00 || key || ser32(index'))`.
+/// The final 32-byte key half is the Ed25519 signing seed.
+pub fn derive_ed25519_path(seed: &[u8], path: &str) -> Result<SecureBuffer, SignerError> {
+    let indices = parse_derivation_path(path)?;
+    let mode = get_locking_mode();
+
+    let mut mac = HmacSha512::new_from_slice(b"ed25519 seed")
+        .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+    mac.update(seed);
+    let mut i = mac.finalize().into_bytes().to_vec();
+
+    let mut key = SecureBuffer::from_slice_with_mode(&i[..ED25519_SEED_SIZE], mode)?;
+    let mut chain_code = SecureBuffer::from_slice_with_mode(&i[ED25519_SEED_SIZE..], mode)?;
+    i.zeroize();
+
+    for index in indices {
+        let mut mac = HmacSha512::new_from_slice(chain_code.as_slice())
+            .map_err(|e| SignerError::KeyDerivationFailed(e.to_string()))?;
+        mac.update(&[0u8]);
+        mac.update(key.as_slice());
+        mac.update(&index.to_be_bytes());
+        let mut i = mac.finalize().into_bytes().to_vec();
+
+        let new_key = SecureBuffer::from_slice_with_mode(&i[..ED25519_SEED_SIZE], mode)?;
+        let new_chain_code = SecureBuffer::from_slice_with_mode(
+            &i[ED25519_SEED_SIZE..ED25519_SEED_SIZE + CHAIN_CODE_SIZE],
+            mode,
+        )?;
+        i.zeroize();
+
+        key.zeroize();
+        chain_code.zeroize();
+        key = new_key;
+        chain_code = new_chain_code;
+    }
+
+    chain_code.zeroize();
+    Ok(key)
+}
+
+/// Derive an Ed25519 seed directly from a BIP39 mnemonic, passphrase, and derivation path.
+///
+/// Convenience wrapper around [`mnemonic_to_seed`] and [`derive_ed25519_path`]
+/// for callers that only care about the final signing seed.
+pub fn derive_ed25519_seed_from_mnemonic(
+    mnemonic: &str,
+    bip39_passphrase: &str,
+    path: &str,
+) -> Result<SecureBuffer, SignerError> {
+    let mut seed = mnemonic_to_seed(mnemonic, bip39_passphrase)?;
+    let result = derive_ed25519_path(seed.as_slice(), path);
+    seed.zeroize();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_derivation_path() {
+        let indices = parse_derivation_path("m/44'/501'/0'/0'").unwrap();
+        assert_eq!(indices, vec![44 | 0x8000_0000, 501 | 0x8000_0000, 0x8000_0000, 0x8000_0000]);
+    }
+
+    #[test]
+    fn test_parse_derivation_path_rejects_non_hardened() {
+        let result = parse_derivation_path("m/44'/501'/0");
+        assert!(matches!(result, Err(SignerError::KeyDerivationFailed(_))));
+    }
+
+    #[test]
+    fn test_derive_ed25519_path_is_deterministic() {
+        let seed = [0x42u8; 64];
+        let key_a = derive_ed25519_path(&seed, "m/44'/501'/0'/0'").unwrap();
+        let key_b = derive_ed25519_path(&seed, "m/44'/501'/0'/0'").unwrap();
+        assert_eq!(key_a.as_slice(), key_b.as_slice());
+        assert_eq!(key_a.len(), ED25519_SEED_SIZE);
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed_length() {
+        let seed = mnemonic_to_seed(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        )
+        .unwrap();
+        assert_eq!(seed.len(), BIP39_SEED_SIZE);
+    }
+}
@@ -33,45 +33,47 @@ pub enum SignerError {
 
     /// Serialization error
     #[error("Serialization error: {0}")]
-    SerializationError(String),
+    SerializationError(#[from] serde_json::Error),
 
     /// Base58 decoding error
     #[error("Base58 decoding error: {0}")]
-    Base58Error(String),
+    Base58Error(#[from] bs58::decode::Error),
 
     /// Base64 decoding error
     #[error("Base64 decoding error: {0}")]
-    Base64Error(String),
+    Base64Error(#[from] base64::DecodeError),
 
     /// Container format error
     #[error("Invalid container format: {0}")]
     ContainerError(String),
 
+    /// A derived key didn't match an embedded/expected public key
+    #[error("Public key does not match derived key")]
+    KeyMismatch,
+
     /// I/O error
     #[error("I/O error: {0}")]
-    IoError(String),
-}
-
-impl From<std::io::Error> for SignerError {
-    fn from(e: std::io::Error) -> Self {
-        SignerError::IoError(e.to_string())
-    }
-}
-
-impl From<base64::DecodeError> for SignerError {
-    fn from(e: base64::DecodeError) -> Self {
-        SignerError::Base64Error(e.to_string())
-    }
-}
-
-impl From<bs58::decode::Error> for SignerError {
-    fn from(e: bs58::decode::Error) -> Self {
-        SignerError::Base58Error(e.to_string())
-    }
+    IoError(#[from] std::io::Error),
 }
 
-impl From<serde_json::Error> for SignerError {
-    fn from(e: serde_json::Error) -> Self {
-        SignerError::SerializationError(e.to_string())
+impl SignerError {
+    /// A stable, machine-readable identifier for this error's kind, for
+    /// callers (the CLI's JSON output, FFI callers) that want to branch on
+    /// error kind instead of string-matching the human-readable message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SignerError::MemoryLockFailed(_) => "memory_lock",
+            SignerError::KeyDerivationFailed(_) => "key_derivation",
+            SignerError::DecryptionFailed => "decrypt",
+            SignerError::InvalidKeyFormat(_) => "invalid_key_format",
+            SignerError::SigningFailed(_) => "signing",
+            SignerError::InvalidTransaction(_) => "invalid_transaction",
+            SignerError::SerializationError(_) => "serialization",
+            SignerError::Base58Error(_) => "base58",
+            SignerError::Base64Error(_) => "base64",
+            SignerError::ContainerError(_) => "container",
+            SignerError::KeyMismatch => "key_mismatch",
+            SignerError::IoError(_) => "io",
+        }
     }
 }
@@ -15,7 +15,17 @@
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
-use crate::crypto::{create_encrypted_key_container, decrypt_and_sign};
+use crate::backend::{sign_with_backend, HardwareBackendOptions};
+use crate::crypto::{create_encrypted_key_container, decrypt_and_sign, Curve};
+
+/// JSON-serializable result of [`signer_sign_secp256k1_recoverable`].
+#[derive(serde::Serialize)]
+struct Secp256k1RecoverableResult {
+    /// Base64-encoded 65-byte `r || s || v` recoverable signature.
+    signature: String,
+    /// Compressed public key, hex-encoded.
+    public_key: String,
+}
 
 /// Result code for FFI operations
 #[repr(C)]
@@ -198,6 +208,79 @@ pub unsafe extern "C" fn signer_sign_direct(
     }
 }
 
+/// Decrypt a secp256k1 key container and produce a 65-byte recoverable
+/// ECDSA signature (`r || s || v`, low-S normalized) over `message_b64`.
+///
+/// # Arguments
+/// * `container_json` - Null-terminated JSON string of the encrypted container
+/// * `passphrase` - Null-terminated passphrase string
+/// * `message_b64` - Base64-encoded message to sign
+///
+/// # Returns
+/// SignerResult with a JSON object `{"signature": "<base64 r||s||v>", "public_key": "<hex>"}`
+/// on success.
+///
+/// # Safety
+/// All pointers must be valid, null-terminated C strings.
+#[no_mangle]
+pub unsafe extern "C" fn signer_sign_secp256k1_recoverable(
+    container_json: *const c_char,
+    passphrase: *const c_char,
+    message_b64: *const c_char,
+) -> SignerResult {
+    if container_json.is_null() || passphrase.is_null() || message_b64.is_null() {
+        return SignerResult::error(1, "Null pointer argument");
+    }
+
+    let container_str = match CStr::from_ptr(container_json).to_str() {
+        Ok(s) => s,
+        Err(_) => return SignerResult::error(2, "Invalid UTF-8 in container"),
+    };
+
+    let passphrase_str = match CStr::from_ptr(passphrase).to_str() {
+        Ok(s) => s,
+        Err(_) => return SignerResult::error(2, "Invalid UTF-8 in passphrase"),
+    };
+
+    let message_str = match CStr::from_ptr(message_b64).to_str() {
+        Ok(s) => s,
+        Err(_) => return SignerResult::error(2, "Invalid UTF-8 in message"),
+    };
+
+    let message_bytes =
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, message_str) {
+            Ok(m) => m,
+            Err(e) => return SignerResult::error(3, &format!("Base64 decode error: {}", e)),
+        };
+
+    let signing_result = match decrypt_and_sign(container_str, passphrase_str, &message_bytes) {
+        Ok(r) => r,
+        Err(e) => return SignerResult::error(4, &e.to_string()),
+    };
+
+    let recovery_id = match signing_result.recovery_id {
+        Some(id) => id,
+        None => return SignerResult::error(6, "container does not hold a secp256k1 key"),
+    };
+
+    let mut signature_bytes = match hex::decode(&signing_result.signature) {
+        Ok(b) => b,
+        Err(e) => return SignerResult::error(3, &format!("hex decode error: {}", e)),
+    };
+    signature_bytes.push(recovery_id);
+
+    let signature_b64 =
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &signature_bytes);
+
+    match serde_json::to_string(&Secp256k1RecoverableResult {
+        signature: signature_b64,
+        public_key: signing_result.public_key,
+    }) {
+        Ok(json) => SignerResult::success(json),
+        Err(e) => SignerResult::error(5, &format!("Serialization error: {}", e)),
+    }
+}
+
 /// Free a string allocated by Rust
 ///
 /// # Safety
@@ -248,6 +331,111 @@ pub extern "C" fn signer_check_mlock_support() -> i32 {
     }
 }
 
+/// List keys available on any compiled-in hardware token / OS keystore
+/// backend (PKCS#11, Windows CNG, macOS Keychain), without exposing key
+/// material.
+///
+/// # Returns
+/// SignerResult with a JSON array of `{"label", "id", "curve"}` objects
+/// (possibly empty if no such backend is compiled in or no token/keystore
+/// is present).
+#[no_mangle]
+pub extern "C" fn signer_list_token_keys() -> SignerResult {
+    match crate::backend::list_token_keys() {
+        Ok(keys) => match serde_json::to_string(&keys) {
+            Ok(json) => SignerResult::success(json),
+            Err(e) => SignerResult::error(5, &format!("Serialization error: {}", e)),
+        },
+        Err(e) => SignerResult::error(4, &e.to_string()),
+    }
+}
+
+/// Sign a message using a key held on a hardware token or OS keystore
+/// (PKCS#11, Windows CNG, or macOS Keychain), selected by name at this
+/// boundary - the same selection point [`crate::backend::sign_with_backend`]
+/// gives the CLI's `sign-hardware` subcommand.
+///
+/// # Arguments
+/// * `backend` - "pkcs11", "cng", or "keychain"
+/// * `selector` - a PKCS#11 label, a CNG key name, or a Keychain label
+/// * `curve` - "ed25519" or "secp256k1"
+/// * `message_b64` - Base64-encoded message to sign
+/// * `module_path` - path to the vendor's PKCS#11 shared library (pkcs11 only); may be null
+/// * `pin` - token PIN (pkcs11 only); may be null
+///
+/// # Returns
+/// SignerResult with JSON signing result on success
+///
+/// # Safety
+/// All non-null pointers must be valid, null-terminated C strings.
+#[no_mangle]
+pub unsafe extern "C" fn signer_sign_with_hardware_backend(
+    backend: *const c_char,
+    selector: *const c_char,
+    curve: *const c_char,
+    message_b64: *const c_char,
+    module_path: *const c_char,
+    pin: *const c_char,
+) -> SignerResult {
+    if backend.is_null() || selector.is_null() || curve.is_null() || message_b64.is_null() {
+        return SignerResult::error(1, "Null pointer argument");
+    }
+
+    macro_rules! read_str {
+        ($ptr:expr) => {
+            match CStr::from_ptr($ptr).to_str() {
+                Ok(s) => s,
+                Err(_) => return SignerResult::error(2, "Invalid UTF-8 argument"),
+            }
+        };
+    }
+
+    let backend_str = read_str!(backend);
+    let selector_str = read_str!(selector);
+    let curve_str = read_str!(curve);
+    let message_str = read_str!(message_b64);
+
+    let backend = match backend_str.parse() {
+        Ok(b) => b,
+        Err(e) => return SignerResult::error(3, &format!("{}", e)),
+    };
+
+    let curve = match curve_str {
+        "ed25519" => Curve::Ed25519,
+        "secp256k1" => Curve::Secp256k1,
+        other => {
+            return SignerResult::error(3, &format!("unknown curve \"{}\"", other));
+        }
+    };
+
+    let message =
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, message_str) {
+            Ok(m) => m,
+            Err(e) => return SignerResult::error(3, &format!("Base64 decode error: {}", e)),
+        };
+
+    let options = HardwareBackendOptions {
+        module_path: if module_path.is_null() {
+            None
+        } else {
+            Some(read_str!(module_path).to_string())
+        },
+        pin: if pin.is_null() {
+            None
+        } else {
+            Some(read_str!(pin).to_string())
+        },
+    };
+
+    match sign_with_backend(backend, selector_str, curve, &message, &options) {
+        Ok(result) => match serde_json::to_string(&result) {
+            Ok(json) => SignerResult::success(json),
+            Err(e) => SignerResult::error(5, &format!("Serialization error: {}", e)),
+        },
+        Err(e) => SignerResult::error(4, &e.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,12 +457,24 @@ mod tests {
             assert!(!result.result.is_null());
 
             let result_str = CStr::from_ptr(result.result).to_str().unwrap();
-            assert!(result_str.contains("\"version\":1"));
+            assert!(result_str.contains("\"version\":2"));
 
             signer_free_result(result);
         }
     }
 
+    #[test]
+    fn test_ffi_list_token_keys_succeeds_with_no_backend_compiled_in() {
+        let result = signer_list_token_keys();
+        assert_eq!(result.error_code, 0);
+
+        unsafe {
+            let result_str = CStr::from_ptr(result.result).to_str().unwrap();
+            assert_eq!(result_str, "[]");
+            signer_free_result(result);
+        }
+    }
+
     #[test]
     fn test_ffi_version() {
         let version_ptr = signer_version();
@@ -19,20 +19,39 @@
 //! - Gets logged or written to disk
 //! - Gets swapped to disk (memory is locked)
 //! - Survives beyond the signing function scope
+//!
+//! # `no_std` / embedded targets
+//!
+//! The crate as a whole (encrypted containers, the CLI, FFI) requires
+//! `std`. The [`secure_buffer`] module's locking/zeroizing core is the
+//! exception: it supports a `std` tier (default), an `alloc`-only tier
+//! that routes warnings through [`secure_buffer::set_log_hook`] instead of
+//! `eprintln!`, and a fully allocation-free tier via
+//! [`secure_buffer::StaticSecureBuffer`] for firmware/enclave targets with
+//! no heap at all. See that module's docs for details.
 
+pub mod audit_log;
+pub mod backend;
 pub mod crypto;
+pub mod derivation;
 pub mod error;
 pub mod secure_buffer;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
+pub use audit_log::{append_entry, verify_log, LogEntry, VerifyResult};
+pub use backend::{Signer, SoftwareSigner, UnlockedSoftwareSigner};
 pub use crypto::{
-    create_encrypted_key_container, decrypt_and_sign, sign_transaction, EncryptedKeyContainer,
-    SigningResult,
+    confirmation_hash, create_encrypted_key_container, decode_transaction, decrypt_and_sign,
+    recover_pubkey, sign_jws, sign_message, sign_offchain_message, sign_transaction,
+    verify_message, DecodedInstruction, DecodedTransaction, EncryptedKeyContainer, SigningResult,
 };
+pub use derivation::{derive_ed25519_path, derive_ed25519_seed_from_mnemonic, mnemonic_to_seed};
 pub use error::SignerError;
-pub use secure_buffer::{LockingMode, SecureBuffer};
+#[cfg(feature = "std")]
+pub use secure_buffer::{DecryptedGuard, EncryptedBuffer};
+pub use secure_buffer::{LockingMode, SecureBuffer, StaticSecureBuffer};
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -12,8 +12,30 @@
 //! # Sign a transaction
 //! solana-signer sign --container <json_file> --passphrase <pass> --transaction <base64>
 //!
+//! # Preview a transaction before signing it
+//! solana-signer decode --transaction <base64>
+//!
+//! # Sign a JWS auth token
+//! solana-signer sign-jws --container <json_file> --passphrase <pass> --payload '{"sub":"alice"}'
+//!
+//! # Sign a message under Solana's off-chain signing domain
+//! solana-signer sign-offchain --key <base58_key> --message <base64>
+//!
 //! # One-shot mode (stdin/stdout)
 //! echo '{"action":"sign",...}' | solana-signer --stdin
+//!
+//! # Remote-signer daemon: unlock containers once, serve POST /sign + GET /keys
+//! solana-signer serve --container <json_file> --passphrase <pass>
+//!
+//! # Append a signed entry to a tamper-evident, hash-chained log
+//! solana-signer log-append --log audit.ndjson --container <json_file> --passphrase <pass> --content "approved payout #42"
+//!
+//! # Verify a log's chain and signatures
+//! solana-signer log-verify --log audit.ndjson
+//!
+//! # Sign with a key held on a hardware token or OS keystore instead of a
+//! # software container
+//! solana-signer sign-hardware --backend pkcs11 --selector my-key --module /usr/lib/softhsm/libsofthsm2.so --pin 1234 --message <base64>
 //! ```
 //!
 //! # Security
@@ -24,13 +46,15 @@
 
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
-use std::io::{self, BufRead, Write};
+use std::io::{self, BufRead, Read, Write};
 
 use solana_secure_signer::{
     create_encrypted_key_container, decrypt_and_sign, sign_transaction, EncryptedKeyContainer,
     SignerError,
 };
 
+mod server;
+
 #[derive(Parser)]
 #[command(name = "solana-signer")]
 #[command(about = "Secure signing core for Solana transactions")]
@@ -78,6 +102,38 @@ enum Commands {
         /// Base64-encoded unsigned transaction
         #[arg(long)]
         transaction: String,
+
+        /// Refuse to sign unless `confirmation` matches the transaction's
+        /// decoded confirmation hash, so a swapped transaction can't be
+        /// blind-signed
+        #[arg(long)]
+        require_preview: bool,
+
+        /// Confirmation hash from `decode`, required when --require-preview is set
+        #[arg(long)]
+        confirmation: Option<String>,
+    },
+
+    /// Decode a transaction into a human-readable preview without signing it
+    Decode {
+        /// Base64-encoded transaction to decode
+        #[arg(long)]
+        transaction: String,
+    },
+
+    /// Sign a JSON payload as a compact EdDSA JWS token (Ed25519 containers only)
+    SignJws {
+        /// Path to encrypted container JSON file, or "-" for stdin
+        #[arg(long)]
+        container: String,
+
+        /// Passphrase for decryption
+        #[arg(long, env = "SIGNER_PASSPHRASE")]
+        passphrase: String,
+
+        /// JWS payload, as a JSON string
+        #[arg(long)]
+        payload: String,
     },
 
     /// Sign directly with a private key (less secure)
@@ -91,8 +147,96 @@ enum Commands {
         message: String,
     },
 
+    /// Sign a message under Solana's off-chain signing domain, so it can
+    /// never be replayed as a transaction
+    SignOffchain {
+        /// Base58-encoded private key
+        #[arg(long, env = "SIGNER_PRIVATE_KEY")]
+        key: String,
+
+        /// Base64-encoded message to sign
+        #[arg(long)]
+        message: String,
+    },
+
     /// Check system capabilities
     Check,
+
+    /// Run a remote-signer daemon: unlock containers once and serve
+    /// signing requests over HTTP instead of re-decrypting per call
+    Serve {
+        /// Path to an encrypted container JSON file to unlock at startup.
+        /// Repeat to serve multiple keys from one daemon.
+        #[arg(long = "container", required = true)]
+        containers: Vec<String>,
+
+        /// Passphrase used to unlock every listed container
+        #[arg(long, env = "SIGNER_PASSPHRASE")]
+        passphrase: String,
+
+        /// Loopback address to bind to (ignored if --unix-socket is set)
+        #[arg(long, default_value = "127.0.0.1:3939")]
+        bind: String,
+
+        /// Serve over a Unix domain socket instead of TCP
+        #[arg(long)]
+        unix_socket: Option<String>,
+    },
+
+    /// Append a signed entry to a hash-chained audit log
+    LogAppend {
+        /// Path to the log file (created if it doesn't exist), or "-" for stdin/stdout
+        #[arg(long)]
+        log: String,
+
+        /// Path to encrypted container JSON file, or "-" for stdin (Ed25519 only)
+        #[arg(long)]
+        container: String,
+
+        /// Passphrase for decryption
+        #[arg(long, env = "SIGNER_PASSPHRASE")]
+        passphrase: String,
+
+        /// The content this entry attests to
+        #[arg(long)]
+        content: String,
+    },
+
+    /// Verify a hash-chained audit log's sequence, links, and signatures
+    LogVerify {
+        /// Path to the log file, or "-" for stdin
+        #[arg(long)]
+        log: String,
+    },
+
+    /// Sign a message using a key held on a hardware token or OS keystore
+    /// (PKCS#11, Windows CNG, or macOS Keychain) instead of a software
+    /// container - the private key never enters this process.
+    SignHardware {
+        /// Which backend to use: pkcs11, cng, or keychain
+        #[arg(long)]
+        backend: String,
+
+        /// Key selector: a PKCS#11 label, a CNG key name, or a Keychain label
+        #[arg(long)]
+        selector: String,
+
+        /// The selected key's curve
+        #[arg(long, default_value = "ed25519")]
+        curve: String,
+
+        /// Base64-encoded message to sign
+        #[arg(long)]
+        message: String,
+
+        /// Path to the vendor's PKCS#11 shared library (pkcs11 backend only)
+        #[arg(long)]
+        module: Option<String>,
+
+        /// Token PIN (pkcs11 backend only)
+        #[arg(long, env = "SIGNER_PKCS11_PIN")]
+        pin: Option<String>,
+    },
 }
 
 /// JSON input format for stdin mode
@@ -109,11 +253,59 @@ enum StdinCommand {
         container: String,
         passphrase: String,
         transaction: String,
+        #[serde(default)]
+        require_preview: bool,
+        #[serde(default)]
+        confirmation: Option<String>,
     },
+    #[serde(rename = "decode")]
+    Decode { transaction: String },
     #[serde(rename = "sign_direct")]
     SignDirect { private_key: String, message: String },
+    #[serde(rename = "sign_offchain")]
+    SignOffchain { private_key: String, message: String },
+    #[serde(rename = "sign_jws")]
+    SignJws {
+        container: String,
+        passphrase: String,
+        payload: String,
+    },
     #[serde(rename = "check")]
     Check,
+    #[serde(rename = "log_append")]
+    LogAppend {
+        log: String,
+        container: String,
+        passphrase: String,
+        content: String,
+    },
+    #[serde(rename = "log_verify")]
+    LogVerify { log: String },
+}
+
+/// Structured error payload: a stable machine-readable `code` plus the
+/// error's full cause chain (its `Display` message, then each successive
+/// `source()`, in order), so automation can branch on error kind instead
+/// of string-matching the top-level message.
+#[derive(Serialize)]
+struct ErrorDetail {
+    code: &'static str,
+    messages: Vec<String>,
+}
+
+impl ErrorDetail {
+    fn from_signer_error(e: &SignerError) -> Self {
+        let mut messages = vec![e.to_string()];
+        let mut source = std::error::Error::source(e);
+        while let Some(cause) = source {
+            messages.push(cause.to_string());
+            source = cause.source();
+        }
+        Self {
+            code: e.code(),
+            messages,
+        }
+    }
 }
 
 /// JSON output format
@@ -123,7 +315,7 @@ struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     data: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    error: Option<ErrorDetail>,
 }
 
 impl Output {
@@ -135,11 +327,26 @@ impl Output {
         }
     }
 
+    /// Build an error payload from an ad hoc message that isn't backed by
+    /// a `SignerError` (malformed input JSON, HTTP-layer failures, etc).
     fn error(msg: &str) -> Self {
         Self {
             success: false,
             data: None,
-            error: Some(msg.to_string()),
+            error: Some(ErrorDetail {
+                code: "error",
+                messages: vec![msg.to_string()],
+            }),
+        }
+    }
+
+    /// Build an error payload from a `SignerError`, preserving its stable
+    /// code and full `source()` chain.
+    fn from_signer_error(e: &SignerError) -> Self {
+        Self {
+            success: false,
+            data: None,
+            error: Some(ErrorDetail::from_signer_error(e)),
         }
     }
 }
@@ -163,12 +370,62 @@ fn main() {
             container,
             passphrase,
             transaction,
-        }) => handle_sign(&container, &passphrase, &transaction),
+            require_preview,
+            confirmation,
+        }) => handle_sign(
+            &container,
+            &passphrase,
+            &transaction,
+            require_preview,
+            confirmation.as_deref(),
+        ),
+
+        Some(Commands::Decode { transaction }) => handle_decode(&transaction),
+
+        Some(Commands::SignJws {
+            container,
+            passphrase,
+            payload,
+        }) => handle_sign_jws(&container, &passphrase, &payload),
 
         Some(Commands::SignDirect { key, message }) => handle_sign_direct(&key, &message),
 
+        Some(Commands::SignOffchain { key, message }) => handle_sign_offchain(&key, &message),
+
         Some(Commands::Check) => handle_check(),
 
+        Some(Commands::Serve {
+            containers,
+            passphrase,
+            bind,
+            unix_socket,
+        }) => handle_serve(&containers, &passphrase, &bind, unix_socket.as_deref()),
+
+        Some(Commands::LogAppend {
+            log,
+            container,
+            passphrase,
+            content,
+        }) => handle_log_append(&log, &container, &passphrase, &content),
+
+        Some(Commands::LogVerify { log }) => handle_log_verify(&log),
+
+        Some(Commands::SignHardware {
+            backend,
+            selector,
+            curve,
+            message,
+            module,
+            pin,
+        }) => handle_sign_hardware(
+            &backend,
+            &selector,
+            &curve,
+            &message,
+            module.as_deref(),
+            pin.as_deref(),
+        ),
+
         None => {
             eprintln!("No command specified. Use --help for usage.");
             std::process::exit(1);
@@ -181,7 +438,7 @@ fn main() {
             println!("{}", serde_json::to_string_pretty(&output).unwrap());
         }
         Err(e) => {
-            let output = Output::error(&e.to_string());
+            let output = Output::from_signer_error(&e);
             eprintln!("{}", serde_json::to_string_pretty(&output).unwrap());
             std::process::exit(1);
         }
@@ -229,19 +486,49 @@ fn process_stdin_command(json: &str) -> Output {
             container,
             passphrase,
             transaction,
-        } => handle_sign_inline(&container, &passphrase, &transaction),
+            require_preview,
+            confirmation,
+        } => handle_sign_inline(
+            &container,
+            &passphrase,
+            &transaction,
+            require_preview,
+            confirmation.as_deref(),
+        ),
+
+        StdinCommand::Decode { transaction } => handle_decode(&transaction),
 
         StdinCommand::SignDirect {
             private_key,
             message,
         } => handle_sign_direct(&private_key, &message),
 
+        StdinCommand::SignOffchain {
+            private_key,
+            message,
+        } => handle_sign_offchain(&private_key, &message),
+
+        StdinCommand::SignJws {
+            container,
+            passphrase,
+            payload,
+        } => handle_sign_jws_inline(&container, &passphrase, &payload),
+
         StdinCommand::Check => handle_check(),
+
+        StdinCommand::LogAppend {
+            log,
+            container,
+            passphrase,
+            content,
+        } => handle_log_append_inline(&log, &container, &passphrase, &content),
+
+        StdinCommand::LogVerify { log } => handle_log_verify_inline(&log),
     };
 
     match result {
         Ok(output) => output,
-        Err(e) => Output::error(&e.to_string()),
+        Err(e) => Output::from_signer_error(&e),
     }
 }
 
@@ -252,8 +539,7 @@ fn handle_create_container(
 ) -> Result<Output, SignerError> {
     // Decode the private key
     let private_key = bs58::decode(key_b58)
-        .into_vec()
-        .map_err(|e| SignerError::Base58Error(e.to_string()))?;
+        .into_vec()?;
 
     // Create the container
     let container_json = create_encrypted_key_container(&private_key, passphrase)?;
@@ -275,32 +561,50 @@ fn handle_sign(
     container_path: &str,
     passphrase: &str,
     transaction_b64: &str,
+    require_preview: bool,
+    confirmation: Option<&str>,
 ) -> Result<Output, SignerError> {
     // Read container
     let container_json = if container_path == "-" {
         let mut input = String::new();
         io::stdin()
-            .read_line(&mut input)
-            .map_err(|e| SignerError::IoError(e.to_string()))?;
+            .read_line(&mut input)?;
         input
     } else {
         std::fs::read_to_string(container_path)?
     };
 
-    handle_sign_inline(&container_json, passphrase, transaction_b64)
+    handle_sign_inline(
+        &container_json,
+        passphrase,
+        transaction_b64,
+        require_preview,
+        confirmation,
+    )
 }
 
 fn handle_sign_inline(
     container_json: &str,
     passphrase: &str,
     transaction_b64: &str,
+    require_preview: bool,
+    confirmation: Option<&str>,
 ) -> Result<Output, SignerError> {
     // Decode transaction
     let transaction_bytes = base64::Engine::decode(
         &base64::engine::general_purpose::STANDARD,
         transaction_b64,
-    )
-    .map_err(|e| SignerError::Base64Error(e.to_string()))?;
+    )?;
+
+    if require_preview {
+        let expected = solana_secure_signer::confirmation_hash(&transaction_bytes)?;
+        if confirmation != Some(expected.as_str()) {
+            return Err(SignerError::InvalidTransaction(
+                "signing requires echoing back the decoded transaction's confirmation hash"
+                    .to_string(),
+            ));
+        }
+    }
 
     // Sign
     let result = decrypt_and_sign(container_json, passphrase, &transaction_bytes)?;
@@ -308,14 +612,54 @@ fn handle_sign_inline(
     Ok(Output::success(serde_json::to_value(&result)?))
 }
 
+fn handle_decode(transaction_b64: &str) -> Result<Output, SignerError> {
+    let transaction_bytes = base64::Engine::decode(
+        &base64::engine::general_purpose::STANDARD,
+        transaction_b64,
+    )?;
+
+    let decoded = solana_secure_signer::decode_transaction(&transaction_bytes)?;
+    let confirmation = solana_secure_signer::confirmation_hash(&transaction_bytes)?;
+
+    let mut value = serde_json::to_value(&decoded)?;
+    value["confirmation_hash"] = serde_json::Value::String(confirmation);
+
+    Ok(Output::success(value))
+}
+
+fn handle_sign_jws(
+    container_path: &str,
+    passphrase: &str,
+    payload: &str,
+) -> Result<Output, SignerError> {
+    // Read container
+    let container_json = if container_path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)?;
+        input
+    } else {
+        std::fs::read_to_string(container_path)?
+    };
+
+    handle_sign_jws_inline(&container_json, passphrase, payload)
+}
+
+fn handle_sign_jws_inline(
+    container_json: &str,
+    passphrase: &str,
+    payload: &str,
+) -> Result<Output, SignerError> {
+    let token = solana_secure_signer::sign_jws(container_json, passphrase, payload)?;
+    Ok(Output::success(serde_json::json!({ "token": token })))
+}
+
 fn handle_sign_direct(key_b58: &str, message_b64: &str) -> Result<Output, SignerError> {
     // Decode inputs
     let private_key = bs58::decode(key_b58)
-        .into_vec()
-        .map_err(|e| SignerError::Base58Error(e.to_string()))?;
+        .into_vec()?;
 
-    let message = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, message_b64)
-        .map_err(|e| SignerError::Base64Error(e.to_string()))?;
+    let message = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, message_b64)?;
 
     // Sign
     let result = sign_transaction(&private_key, &message)?;
@@ -323,6 +667,148 @@ fn handle_sign_direct(key_b58: &str, message_b64: &str) -> Result<Output, Signer
     Ok(Output::success(serde_json::to_value(&result)?))
 }
 
+fn handle_serve(
+    containers: &[String],
+    passphrase: &str,
+    bind: &str,
+    unix_socket: Option<&str>,
+) -> Result<Output, SignerError> {
+    server::run(containers, passphrase, bind, unix_socket)
+}
+
+fn handle_sign_offchain(key_b58: &str, message_b64: &str) -> Result<Output, SignerError> {
+    // Decode inputs
+    let private_key = bs58::decode(key_b58)
+        .into_vec()?;
+
+    let message = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, message_b64)?;
+
+    // Sign under Solana's off-chain signing domain, not raw
+    let result = solana_secure_signer::sign_offchain_message(&private_key, &message)?;
+
+    Ok(Output::success(serde_json::to_value(&result)?))
+}
+
+fn handle_log_append(
+    log_path: &str,
+    container_path: &str,
+    passphrase: &str,
+    content: &str,
+) -> Result<Output, SignerError> {
+    // Read container
+    let container_json = if container_path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)?;
+        input
+    } else {
+        std::fs::read_to_string(container_path)?
+    };
+
+    // Read the existing log, treating a missing file as an empty log
+    let existing_log = if log_path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        std::fs::read_to_string(log_path).unwrap_or_default()
+    };
+
+    let output = handle_log_append_inline(&existing_log, &container_json, passphrase, content)?;
+
+    if log_path != "-" {
+        if let Some(log_text) = output
+            .data
+            .as_ref()
+            .and_then(|data| data.get("log"))
+            .and_then(|v| v.as_str())
+        {
+            std::fs::write(log_path, log_text)?;
+        }
+    }
+
+    Ok(output)
+}
+
+fn handle_log_append_inline(
+    existing_log: &str,
+    container_json: &str,
+    passphrase: &str,
+    content: &str,
+) -> Result<Output, SignerError> {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| SignerError::IoError(std::io::Error::new(std::io::ErrorKind::Other, e)))?
+        .as_secs();
+
+    let updated_log =
+        solana_secure_signer::append_entry(existing_log, container_json, passphrase, content, timestamp)?;
+
+    let entry: serde_json::Value = match updated_log.lines().last() {
+        Some(line) => serde_json::from_str(line)?,
+        None => {
+            use serde::de::Error as _;
+            return Err(SignerError::SerializationError(serde_json::Error::custom(
+                "append produced an empty log",
+            )));
+        }
+    };
+
+    Ok(Output::success(serde_json::json!({
+        "log": updated_log,
+        "entry": entry,
+    })))
+}
+
+fn handle_log_verify(log_path: &str) -> Result<Output, SignerError> {
+    let log = if log_path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        std::fs::read_to_string(log_path)?
+    };
+
+    handle_log_verify_inline(&log)
+}
+
+fn handle_log_verify_inline(log: &str) -> Result<Output, SignerError> {
+    let result = solana_secure_signer::verify_log(log)?;
+    Ok(Output::success(serde_json::to_value(&result)?))
+}
+
+fn handle_sign_hardware(
+    backend: &str,
+    selector: &str,
+    curve: &str,
+    message_b64: &str,
+    module: Option<&str>,
+    pin: Option<&str>,
+) -> Result<Output, SignerError> {
+    use solana_secure_signer::backend::{sign_with_backend, HardwareBackendOptions};
+
+    let backend = backend.parse()?;
+    let curve = match curve {
+        "ed25519" => solana_secure_signer::crypto::Curve::Ed25519,
+        "secp256k1" => solana_secure_signer::crypto::Curve::Secp256k1,
+        other => {
+            return Err(SignerError::KeyDerivationFailed(format!(
+                "unknown curve \"{}\" (expected ed25519 or secp256k1)",
+                other
+            )))
+        }
+    };
+    let message = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, message_b64)?;
+
+    let options = HardwareBackendOptions {
+        module_path: module.map(str::to_string),
+        pin: pin.map(str::to_string),
+    };
+
+    let result = sign_with_backend(backend, selector, curve, &message, &options)?;
+    Ok(Output::success(serde_json::to_value(&result)?))
+}
+
 fn handle_check() -> Result<Output, SignerError> {
     use solana_secure_signer::SecureBuffer;
 
@@ -5,13 +5,83 @@
 //! - Automatically zeroizes on drop
 //! - Handles panic-safe cleanup
 //! - Prevents copies of sensitive data
+//!
+//! # Feature tiers
+//!
+//! - `std` (default): the full [`SecureBuffer`]/[`EncryptedBuffer`] API,
+//!   `Vec`-backed, with OS mlock/madvise/prctl support and warnings printed
+//!   to stderr.
+//! - `alloc` (no default `std`): the same `Vec`-backed [`SecureBuffer`],
+//!   but warnings are routed through [`set_log_hook`] instead of
+//!   `eprintln!`, since there may be no stdout/stderr.
+//! - neither: only [`StaticSecureBuffer`] is available, operating over a
+//!   caller-provided `&'static mut [u8]` (e.g. a fixed SRAM region or
+//!   enclave-mapped memory) so no allocator is required at all.
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{string::ToString, vec, vec::Vec};
+
+use core::cmp::Ordering;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{compiler_fence, AtomicPtr, Ordering as AtomicOrdering};
+
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "std")]
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+#[cfg(feature = "std")]
+use chacha20::ChaCha20;
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+#[cfg(feature = "std")]
+use rand::RngCore;
 
-use std::ops::{Deref, DerefMut};
-use std::ptr;
 use zeroize::Zeroize;
 
 use crate::error::SignerError;
 
+#[cfg(feature = "std")]
+const CHACHA20_KEY_SIZE: usize = 32;
+#[cfg(feature = "std")]
+const CHACHA20_NONCE_SIZE: usize = 12;
+
+/// A hook for routing `SecureBuffer`'s warnings (e.g. "mlock failed")
+/// somewhere other than stderr - required on `alloc`-only targets that
+/// have no stdio, and optional (but available) under `std`.
+pub type LogHook = fn(&str);
+
+static LOG_HOOK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Install a hook to receive `SecureBuffer`'s warning messages. Overwrites
+/// any previously installed hook. Pass `None` to go back to the default
+/// (stderr under `std`, silent otherwise).
+pub fn set_log_hook(hook: Option<LogHook>) {
+    let ptr = match hook {
+        Some(f) => f as *mut (),
+        None => core::ptr::null_mut(),
+    };
+    LOG_HOOK.store(ptr, AtomicOrdering::SeqCst);
+}
+
+fn log_warning(message: &str) {
+    let ptr = LOG_HOOK.load(AtomicOrdering::SeqCst);
+    if !ptr.is_null() {
+        let hook: LogHook = unsafe { core::mem::transmute(ptr) };
+        hook(message);
+        return;
+    }
+
+    #[cfg(feature = "std")]
+    eprintln!("{}", message);
+}
+
 /// A secure buffer that locks its memory and zeroizes on drop
 ///
 /// # Memory Lifecycle
@@ -44,6 +114,11 @@ pub enum LockingMode {
     Strict,
     /// Allow fallback if mlock fails (less secure, logs warning)
     Permissive,
+    /// No OS locking primitive exists (bare metal, no MMU) and the caller
+    /// guarantees the backing memory is already non-swappable - e.g. SRAM
+    /// or an enclave-mapped region. Skips the lock syscall entirely and
+    /// is treated as locked for `Strict` purposes.
+    CallerAsserted,
 }
 
 impl SecureBuffer {
@@ -77,8 +152,13 @@ impl SecureBuffer {
     pub fn with_mode(capacity: usize, mode: LockingMode) -> Result<Self, SignerError> {
         let data = vec![0u8; capacity];
 
-        // Lock the memory to prevent swapping
-        let locked = lock_memory(&data);
+        // CallerAsserted skips the syscall: there's no lock primitive to
+        // call, and the caller has already promised the memory is safe.
+        let locked = if mode == LockingMode::CallerAsserted {
+            true
+        } else {
+            lock_memory(&data)
+        };
 
         if mode == LockingMode::Strict && !locked {
             return Err(SignerError::MemoryLockFailed(
@@ -88,7 +168,7 @@ impl SecureBuffer {
         }
 
         if !locked {
-            eprintln!(
+            log_warning(
                 "Warning: Memory locking failed. Private keys may be swapped to disk. \
                  Consider running with elevated privileges or increasing ulimit -l."
             );
@@ -131,6 +211,33 @@ impl SecureBuffer {
         Self::from_slice_with_mode(source, LockingMode::Permissive)
     }
 
+    /// Encrypt `source` into an [`EncryptedBuffer`] instead of storing it as
+    /// plaintext at rest. Prefer this over `from_slice` for data that is
+    /// held for a long time (e.g. an idle signing key) but only needs to be
+    /// decrypted in short bursts.
+    ///
+    /// Requires the `std` feature (see [`EncryptedBuffer`]).
+    #[cfg(feature = "std")]
+    pub fn encrypted(source: &[u8]) -> Result<EncryptedBuffer, SignerError> {
+        EncryptedBuffer::encrypt(source)
+    }
+
+    /// Best-effort, process-wide hardening against memory inspection: marks
+    /// the process non-dumpable so core dumps and `ptrace` attaches can't
+    /// recover locked secrets, on platforms that support it.
+    ///
+    /// This is independent of any single buffer's locking and only needs to
+    /// be called once, e.g. near process startup before any key material is
+    /// handled.
+    ///
+    /// # Returns
+    /// `true` if hardening was applied, `false` if this platform has no
+    /// supported mechanism or the call failed. The process keeps running
+    /// either way - this is defense in depth, not a required precondition.
+    pub fn harden_process() -> bool {
+        harden_process_impl()
+    }
+
     /// Get the length of the buffer
     pub fn len(&self) -> usize {
         self.data.len()
@@ -171,6 +278,64 @@ impl SecureBuffer {
         self.data.zeroize();
     }
 
+    /// Compare against `other` in constant time.
+    ///
+    /// Reads every byte via `ptr::read_volatile` and accumulates
+    /// differences with bitwise OR across the full length, never branching
+    /// or returning early on a mismatch - so timing doesn't leak which
+    /// byte (or whether any byte) differed. A length mismatch is checked
+    /// up front since length is ordinarily public (e.g. a MAC's expected
+    /// size), not part of the secret being compared.
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        if self.data.len() != other.len() {
+            return false;
+        }
+
+        let mut diff: u8 = 0;
+        for (a, b) in self.data.iter().zip(other.iter()) {
+            let av = unsafe { ptr::read_volatile(a) };
+            let bv = unsafe { ptr::read_volatile(b) };
+            diff |= av ^ bv;
+        }
+        compiler_fence(AtomicOrdering::SeqCst);
+
+        diff == 0
+    }
+
+    /// Compare against `other` in constant time, returning an [`Ordering`].
+    ///
+    /// Folds a running "first difference" result across every compared
+    /// byte without branching or returning early: each byte contributes
+    /// `-1`/`0`/`1` to the result only while no earlier byte has already
+    /// decided it, using arithmetic instead of an early exit. A trailing
+    /// length comparison is folded in the same way for slices that agree
+    /// on every compared byte but differ in length.
+    pub fn ct_cmp(&self, other: &[u8]) -> Ordering {
+        let len = self.data.len().min(other.len());
+        let mut result: i32 = 0;
+        let mut decided: i32 = 0;
+
+        for i in 0..len {
+            let a = unsafe { ptr::read_volatile(&self.data[i]) } as i32;
+            let b = unsafe { ptr::read_volatile(&other[i]) } as i32;
+            let diff = (a > b) as i32 - (a < b) as i32;
+            let undecided = 1 - decided;
+            result |= diff * undecided;
+            decided |= (diff != 0) as i32;
+        }
+        compiler_fence(AtomicOrdering::SeqCst);
+
+        let length_diff = (self.data.len() > other.len()) as i32 - (self.data.len() < other.len()) as i32;
+        let undecided = 1 - decided;
+        result |= length_diff * undecided;
+
+        match result.signum() {
+            -1 => Ordering::Less,
+            1 => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+
     /// Resize the buffer (maintains strict locking requirement)
     ///
     /// Note: This may cause reallocation. The old memory is zeroized
@@ -248,9 +413,20 @@ impl DerefMut for SecureBuffer {
     }
 }
 
+/// `==` on a `SecureBuffer` always runs in constant time via [`SecureBuffer::ct_eq`],
+/// so comparing secret bytes is safe by default even if a caller forgets
+/// to reach for `ct_eq` explicitly.
+impl PartialEq for SecureBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(&other.data)
+    }
+}
+
+impl Eq for SecureBuffer {}
+
 // Prevent accidental debug printing of sensitive data
-impl std::fmt::Debug for SecureBuffer {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for SecureBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SecureBuffer")
             .field("len", &self.data.len())
             .field("is_locked", &self.is_locked)
@@ -260,6 +436,11 @@ impl std::fmt::Debug for SecureBuffer {
 }
 
 /// Lock memory to prevent swapping (platform-specific)
+///
+/// On Unix this also excludes the region from core dumps (see
+/// [`exclude_from_core_dumps`]); under `LockingMode::Strict` a failure to
+/// do so is treated the same as a failed `mlock`, since a core dump is
+/// just as effective a leak vector as swap.
 #[cfg(unix)]
 fn lock_memory(data: &[u8]) -> bool {
     use std::ffi::c_void;
@@ -273,8 +454,53 @@ fn lock_memory(data: &[u8]) -> bool {
         let len = data.len();
 
         // mlock() locks the memory region containing the specified address range
-        libc::mlock(ptr, len) == 0
+        if libc::mlock(ptr, len) != 0 {
+            return false;
+        }
+    }
+
+    exclude_from_core_dumps(data)
+}
+
+/// Exclude a locked memory region from core dumps, on platforms that
+/// support it. Returns `true` if no such exclusion is needed to consider
+/// the region "locked" (either it succeeded, or the platform has no
+/// portable primitive for it).
+#[cfg(target_os = "linux")]
+fn exclude_from_core_dumps(data: &[u8]) -> bool {
+    use std::ffi::c_void;
+
+    if data.is_empty() {
+        return true;
     }
+
+    unsafe { libc::madvise(data.as_ptr() as *mut c_void, data.len(), libc::MADV_DONTDUMP) == 0 }
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+fn exclude_from_core_dumps(data: &[u8]) -> bool {
+    use std::ffi::c_void;
+
+    if data.is_empty() {
+        return true;
+    }
+
+    unsafe {
+        let ptr = data.as_ptr() as *mut c_void;
+        let len = data.len();
+        libc::madvise(ptr, len, libc::MADV_NOCORE) == 0
+            && libc::minherit(ptr, len, libc::INHERIT_NONE) == 0
+    }
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "linux", target_os = "freebsd", target_os = "dragonfly"))
+))]
+fn exclude_from_core_dumps(_data: &[u8]) -> bool {
+    // No portable core-dump exclusion primitive on this platform (e.g.
+    // macOS); mlock alone still keeps the pages out of swap.
+    true
 }
 
 #[cfg(unix)]
@@ -326,9 +552,10 @@ fn unlock_memory(data: &[u8]) {
 
 #[cfg(not(any(unix, windows)))]
 fn lock_memory(_data: &[u8]) -> bool {
-    // Platform doesn't support memory locking
-    // Continue anyway but log a warning
-    eprintln!("Warning: Memory locking not supported on this platform");
+    // No lock primitive on this platform (e.g. bare metal). Continue
+    // anyway but warn - callers on such targets should use
+    // `LockingMode::CallerAsserted` if the memory is already known-safe.
+    log_warning("Warning: Memory locking not supported on this platform");
     false
 }
 
@@ -337,6 +564,18 @@ fn unlock_memory(_data: &[u8]) {
     // No-op on unsupported platforms
 }
 
+/// Disable core dumps for the whole process via `prctl(PR_SET_DUMPABLE, 0)`.
+#[cfg(target_os = "linux")]
+fn harden_process_impl() -> bool {
+    unsafe { libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) == 0 }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn harden_process_impl() -> bool {
+    // No portable equivalent of PR_SET_DUMPABLE outside Linux.
+    false
+}
+
 /// A guard that holds a secure reference and zeroizes on drop
 ///
 /// Useful for temporary access to sensitive data within a scope.
@@ -373,7 +612,283 @@ impl<'a> Drop for SecureGuard<'a> {
                 ptr::write_volatile(byte, 0);
             }
         }
-        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+        compiler_fence(AtomicOrdering::SeqCst);
+    }
+}
+
+/// A process-lifetime random value used to mask each `EncryptedBuffer`'s
+/// session key at rest. The mask and the masked key are never stored on the
+/// same page, so a single leaked page is insufficient to recover the key.
+#[cfg(feature = "std")]
+fn process_pre_key() -> &'static [u8; CHACHA20_KEY_SIZE] {
+    static PRE_KEY: OnceLock<[u8; CHACHA20_KEY_SIZE]> = OnceLock::new();
+    PRE_KEY.get_or_init(|| {
+        let mut key = [0u8; CHACHA20_KEY_SIZE];
+        OsRng.fill_bytes(&mut key);
+        key
+    })
+}
+
+/// A buffer that keeps its payload encrypted at rest in locked memory,
+/// decrypting it only for the lifetime of a short-lived [`DecryptedGuard`].
+///
+/// # Security Model
+///
+/// - The payload is encrypted in place with ChaCha20 as soon as it is
+///   constructed; only ciphertext sits in the long-lived buffer.
+/// - The session key lives in its own, separately locked [`SecureBuffer`]
+///   so the key and the ciphertext never share a memory page.
+/// - The session key is itself XOR-masked with a process-lifetime pre-key
+///   (see [`process_pre_key`]) before it is stored, so a single leaked page
+///   - of either the ciphertext buffer or the key buffer - is insufficient
+///   to recover the plaintext.
+/// - [`EncryptedBuffer::access`] decrypts into a freshly allocated locked
+///   buffer held by the returned guard; the guard zeroizes it on drop and
+///   re-encrypts any changes back into `self`.
+///
+/// Requires the `std` feature: the session key is drawn from the OS RNG.
+#[cfg(feature = "std")]
+pub struct EncryptedBuffer {
+    ciphertext: SecureBuffer,
+    masked_session_key: SecureBuffer,
+    nonce: [u8; CHACHA20_NONCE_SIZE],
+    mode: LockingMode,
+}
+
+#[cfg(feature = "std")]
+impl EncryptedBuffer {
+    /// Encrypt `plaintext` into a new `EncryptedBuffer` with strict locking.
+    ///
+    /// The source slice is not zeroized; callers should zeroize it
+    /// themselves once this returns.
+    pub fn encrypt(plaintext: &[u8]) -> Result<Self, SignerError> {
+        Self::encrypt_with_mode(plaintext, LockingMode::Strict)
+    }
+
+    /// Encrypt `plaintext` into a new `EncryptedBuffer` with configurable locking.
+    pub fn encrypt_with_mode(plaintext: &[u8], mode: LockingMode) -> Result<Self, SignerError> {
+        let mut session_key = [0u8; CHACHA20_KEY_SIZE];
+        let mut nonce = [0u8; CHACHA20_NONCE_SIZE];
+        OsRng.fill_bytes(&mut session_key);
+        OsRng.fill_bytes(&mut nonce);
+
+        let mut ciphertext = SecureBuffer::from_slice_with_mode(plaintext, mode)?;
+        apply_chacha20(&session_key, &nonce, ciphertext.as_mut_slice());
+
+        let masked_session_key = mask_session_key(&session_key);
+        session_key.zeroize();
+        let masked_session_key = SecureBuffer::from_slice_with_mode(&masked_session_key, mode)?;
+
+        Ok(Self {
+            ciphertext,
+            masked_session_key,
+            nonce,
+            mode,
+        })
+    }
+
+    /// Get the length of the (decrypted) payload.
+    pub fn len(&self) -> usize {
+        self.ciphertext.len()
+    }
+
+    /// Check if the payload is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ciphertext.is_empty()
+    }
+
+    /// Decrypt the payload into a short-lived guard.
+    ///
+    /// The guard holds the plaintext in a freshly allocated locked buffer
+    /// and zeroizes it on drop. Any mutation made through the guard is
+    /// re-encrypted back into `self` when the guard drops.
+    pub fn access(&mut self) -> Result<DecryptedGuard<'_>, SignerError> {
+        let session_key = self.unmask_session_key();
+        let mut plaintext =
+            SecureBuffer::from_slice_with_mode(self.ciphertext.as_slice(), self.mode)?;
+        apply_chacha20(&session_key, &self.nonce, plaintext.as_mut_slice());
+
+        Ok(DecryptedGuard {
+            owner: self,
+            plaintext,
+        })
+    }
+
+    fn unmask_session_key(&self) -> [u8; CHACHA20_KEY_SIZE] {
+        unmask_session_key(self.masked_session_key.as_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+fn apply_chacha20(key: &[u8; CHACHA20_KEY_SIZE], nonce: &[u8; CHACHA20_NONCE_SIZE], data: &mut [u8]) {
+    let mut cipher = ChaCha20::new(key.into(), nonce.into());
+    cipher.apply_keystream(data);
+}
+
+#[cfg(feature = "std")]
+fn mask_session_key(session_key: &[u8; CHACHA20_KEY_SIZE]) -> [u8; CHACHA20_KEY_SIZE] {
+    let pre_key = process_pre_key();
+    let mut masked = [0u8; CHACHA20_KEY_SIZE];
+    for ((m, k), p) in masked.iter_mut().zip(session_key.iter()).zip(pre_key.iter()) {
+        *m = k ^ p;
+    }
+    masked
+}
+
+#[cfg(feature = "std")]
+fn unmask_session_key(masked_session_key: &[u8]) -> [u8; CHACHA20_KEY_SIZE] {
+    let pre_key = process_pre_key();
+    let mut key = [0u8; CHACHA20_KEY_SIZE];
+    for ((k, m), p) in key.iter_mut().zip(masked_session_key.iter()).zip(pre_key.iter()) {
+        *k = m ^ p;
+    }
+    key
+}
+
+/// A short-lived, decrypted view of an [`EncryptedBuffer`]'s payload.
+///
+/// The plaintext lives in a freshly allocated locked buffer for the
+/// lifetime of the guard only. On drop, the (possibly mutated) plaintext
+/// is re-encrypted back into the owning `EncryptedBuffer` and the guard's
+/// own buffer is zeroized.
+#[cfg(feature = "std")]
+pub struct DecryptedGuard<'a> {
+    owner: &'a mut EncryptedBuffer,
+    plaintext: SecureBuffer,
+}
+
+#[cfg(feature = "std")]
+impl<'a> Deref for DecryptedGuard<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.plaintext.as_slice()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> DerefMut for DecryptedGuard<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.plaintext.as_mut_slice()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Drop for DecryptedGuard<'a> {
+    fn drop(&mut self) {
+        let session_key = self.owner.unmask_session_key();
+        // Encrypt in place within self.plaintext - which is locked memory,
+        // unlike an ordinary Vec - then copy the now-ciphertext bytes out.
+        // That keeps the plaintext from ever existing outside locked memory,
+        // and self.plaintext still zeroizes itself via SecureBuffer's Drop.
+        apply_chacha20(&session_key, &self.owner.nonce, self.plaintext.as_mut_slice());
+        self.owner
+            .ciphertext
+            .as_mut_slice()
+            .copy_from_slice(self.plaintext.as_slice());
+    }
+}
+
+/// A secure buffer over caller-provided static storage, for targets with no
+/// heap allocator at all (e.g. firmware, or a TEE with a fixed memory map).
+///
+/// Unlike [`SecureBuffer`], this never allocates: the caller supplies a
+/// `&'static mut [u8]` - typically memory the linker placed in a dedicated,
+/// non-swappable section - and this type only adds the locking attempt,
+/// zeroize-on-drop, and debug redaction on top of it. Use
+/// `LockingMode::CallerAsserted` on targets with no lock primitive at all,
+/// where the memory is already known to be safe.
+///
+/// Note: the error path still returns [`SignerError`], whose string
+/// payloads currently require `alloc`; only the buffer itself is
+/// allocation-free.
+pub struct StaticSecureBuffer {
+    data: &'static mut [u8],
+    is_locked: bool,
+}
+
+impl StaticSecureBuffer {
+    /// Wrap `data` as a secure buffer, attempting to lock it the same way
+    /// [`SecureBuffer`] does.
+    pub fn new(data: &'static mut [u8], mode: LockingMode) -> Result<Self, SignerError> {
+        let locked = if mode == LockingMode::CallerAsserted {
+            true
+        } else {
+            lock_memory(data)
+        };
+
+        if mode == LockingMode::Strict && !locked {
+            return Err(SignerError::MemoryLockFailed(
+                "mlock failed on static buffer and no CallerAsserted override was given"
+                    .to_string(),
+            ));
+        }
+
+        Ok(Self { data, is_locked: locked })
+    }
+
+    /// Get the length of the buffer.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Check if memory is locked.
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+
+    /// Get a reference to the underlying data.
+    pub fn as_slice(&self) -> &[u8] {
+        self.data
+    }
+
+    /// Get a mutable reference to the underlying data.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.data
+    }
+
+    /// Explicitly zeroize the buffer contents. Also called on drop.
+    pub fn zeroize(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+impl Drop for StaticSecureBuffer {
+    fn drop(&mut self) {
+        self.data.zeroize();
+        if self.is_locked {
+            unlock_memory(self.data);
+        }
+        // The backing storage is 'static and is never deallocated here.
+    }
+}
+
+impl Deref for StaticSecureBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.data
+    }
+}
+
+impl DerefMut for StaticSecureBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.data
+    }
+}
+
+impl core::fmt::Debug for StaticSecureBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticSecureBuffer")
+            .field("len", &self.data.len())
+            .field("is_locked", &self.is_locked)
+            .field("data", &"[REDACTED]")
+            .finish()
     }
 }
 
@@ -426,4 +941,109 @@ mod tests {
             Err(e) => panic!("Unexpected error: {}", e),
         }
     }
+
+    #[test]
+    fn test_encrypted_buffer_roundtrip() {
+        let secret = b"super secret key material";
+        let mut buffer = EncryptedBuffer::encrypt_with_mode(secret, LockingMode::Permissive).unwrap();
+        assert_ne!(buffer.ciphertext.as_slice(), secret);
+
+        let guard = buffer.access().unwrap();
+        assert_eq!(&*guard, secret);
+    }
+
+    #[test]
+    fn test_encrypted_buffer_guard_mutation_is_resealed() {
+        let mut buffer =
+            EncryptedBuffer::encrypt_with_mode(&[0u8; 8], LockingMode::Permissive).unwrap();
+
+        {
+            let mut guard = buffer.access().unwrap();
+            guard.copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        let guard = buffer.access().unwrap();
+        assert_eq!(&*guard, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_ct_eq_matches_equal_and_unequal_data() {
+        let buffer = SecureBuffer::from_slice_permissive(&[1, 2, 3, 4]).unwrap();
+        assert!(buffer.ct_eq(&[1, 2, 3, 4]));
+        assert!(!buffer.ct_eq(&[1, 2, 3, 5]));
+        assert!(!buffer.ct_eq(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_partial_eq_uses_ct_eq() {
+        let a = SecureBuffer::from_slice_permissive(&[9, 9, 9]).unwrap();
+        let b = SecureBuffer::from_slice_permissive(&[9, 9, 9]).unwrap();
+        let c = SecureBuffer::from_slice_permissive(&[9, 9, 8]).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_ct_cmp_orders_like_slice_comparison() {
+        let buffer = SecureBuffer::from_slice_permissive(&[1, 2, 3]).unwrap();
+        assert_eq!(buffer.ct_cmp(&[1, 2, 3]), Ordering::Equal);
+        assert_eq!(buffer.ct_cmp(&[1, 2, 4]), Ordering::Less);
+        assert_eq!(buffer.ct_cmp(&[1, 2, 2]), Ordering::Greater);
+        assert_eq!(buffer.ct_cmp(&[1, 2, 3, 0]), Ordering::Less);
+        assert_eq!(buffer.ct_cmp(&[1, 2]), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_static_secure_buffer_zeroizes_on_drop() {
+        static mut BACKING: [u8; 4] = [0u8; 4];
+
+        // SAFETY: test-only, single-threaded access to a static backing array.
+        let backing: &'static mut [u8] = unsafe { &mut *core::ptr::addr_of_mut!(BACKING) };
+        {
+            let mut buffer =
+                StaticSecureBuffer::new(backing, LockingMode::CallerAsserted).unwrap();
+            buffer.as_mut_slice().copy_from_slice(&[1, 2, 3, 4]);
+            assert_eq!(buffer.as_slice(), &[1, 2, 3, 4]);
+        }
+
+        // SAFETY: the buffer above has been dropped, so nothing else aliases BACKING.
+        unsafe {
+            assert_eq!(core::ptr::addr_of!(BACKING).read(), [0u8; 4]);
+        }
+    }
+
+    #[test]
+    fn test_log_hook_receives_warnings() {
+        use std::sync::{Mutex, OnceLock};
+
+        static LAST_MESSAGE: OnceLock<Mutex<Option<String>>> = OnceLock::new();
+        LAST_MESSAGE.get_or_init(|| Mutex::new(None));
+
+        fn hook(message: &str) {
+            *LAST_MESSAGE.get().unwrap().lock().unwrap() = Some(message.to_string());
+        }
+
+        set_log_hook(Some(hook));
+        log_warning("test warning");
+        assert_eq!(
+            LAST_MESSAGE.get().unwrap().lock().unwrap().as_deref(),
+            Some("test warning")
+        );
+        set_log_hook(None);
+    }
+
+    #[test]
+    fn test_harden_process_does_not_panic() {
+        // Best-effort; just make sure it returns without crashing on this platform.
+        let _ = SecureBuffer::harden_process();
+    }
+
+    #[test]
+    fn test_encrypted_buffer_key_and_ciphertext_are_separate_allocations() {
+        let buffer = EncryptedBuffer::encrypt_with_mode(b"payload", LockingMode::Permissive).unwrap();
+        assert_ne!(
+            buffer.ciphertext.as_slice().as_ptr(),
+            buffer.masked_session_key.as_slice().as_ptr()
+        );
+    }
 }
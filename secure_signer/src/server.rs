@@ -0,0 +1,271 @@
+//! HTTP daemon for `serve` mode.
+//!
+//! Loads one or more encrypted key containers once at startup and keeps
+//! each one's key decrypted in locked memory for the life of the process
+//! (see [`solana_secure_signer::backend::UnlockedSoftwareSigner`]), instead
+//! of re-deriving the Argon2 key and decrypting the container on every
+//! request. Exposes `POST /sign` and `GET /keys` over a minimal hand-rolled
+//! HTTP/1.1 loop - a full HTTP stack is unwarranted for a two-route,
+//! loopback-only daemon, and keeping the one process that holds private
+//! key material free of extra dependencies is worth the extra few lines.
+//!
+//! Binds to a loopback TCP address or a Unix domain socket only - there is
+//! no flag to listen on a non-loopback address. Every request must carry a
+//! `SIGNER_API_TOKEN` bearer token; the daemon refuses to start if that
+//! variable isn't set, since an unauthenticated signer that produces
+//! signatures on demand is too dangerous to default to.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+
+use solana_secure_signer::backend::UnlockedSoftwareSigner;
+use solana_secure_signer::{SecureBuffer, Signer, SignerError};
+
+use crate::Output;
+
+/// Environment variable holding the bearer token required on every request.
+const ENV_API_TOKEN: &str = "SIGNER_API_TOKEN";
+
+/// Refuse request bodies larger than this before allocating or reading
+/// them, so an unauthenticated client can't force a large allocation (or a
+/// slow blocking read) with an oversized `Content-Length`.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Unlocked signers, keyed by public key, plus the token every request must present.
+///
+/// `token` is a `SecureBuffer` (not a `String`) so it can be compared
+/// against a presented token with [`SecureBuffer::ct_eq`] instead of `==`,
+/// which would leak timing information about how many leading bytes of
+/// the token are correct.
+struct Registry {
+    signers: HashMap<String, UnlockedSoftwareSigner>,
+    token: SecureBuffer,
+}
+
+/// Unlock `container_paths` with `passphrase` and serve `POST /sign` /
+/// `GET /keys` until the process is killed.
+///
+/// Binds to `bind` (a loopback `host:port`) unless `unix_socket` is given,
+/// in which case it listens on that Unix domain socket instead.
+pub fn run(
+    container_paths: &[String],
+    passphrase: &str,
+    bind: &str,
+    unix_socket: Option<&str>,
+) -> Result<Output, SignerError> {
+    let token = std::env::var(ENV_API_TOKEN).map_err(|_| {
+        SignerError::ContainerError(format!(
+            "{} must be set - serve mode refuses to start without an API token",
+            ENV_API_TOKEN
+        ))
+    })?;
+    let token = SecureBuffer::from_slice(token.as_bytes())?;
+
+    let mut signers = HashMap::new();
+    for path in container_paths {
+        let container_json = std::fs::read_to_string(path)?;
+        let signer = UnlockedSoftwareSigner::unlock(&container_json, passphrase)?;
+        signers.insert(signer.public_key()?, signer);
+    }
+
+    let registry = Registry { signers, token };
+
+    match unix_socket {
+        Some(socket_path) => serve_unix(socket_path, &registry)?,
+        None => serve_tcp(bind, &registry)?,
+    }
+
+    Ok(Output::success(serde_json::json!({ "stopped": true })))
+}
+
+fn serve_tcp(bind: &str, registry: &Registry) -> Result<(), SignerError> {
+    let addr = bind
+        .to_socket_addrs()
+        .map_err(|e| {
+            SignerError::IoError(std::io::Error::new(
+                e.kind(),
+                format!("invalid bind address: {}", e),
+            ))
+        })?
+        .next()
+        .ok_or_else(|| {
+            SignerError::IoError(std::io::Error::new(
+                std::io::ErrorKind::AddrNotAvailable,
+                "bind address resolved to no addresses",
+            ))
+        })?;
+
+    if !addr.ip().is_loopback() {
+        return Err(SignerError::IoError(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "serve mode only binds to loopback addresses; use --unix-socket for local IPC instead",
+        )));
+    }
+
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("serve: listening on {}", addr);
+
+    for stream in listener.incoming() {
+        let stream: TcpStream = stream?;
+        if let Err(e) = handle_connection(stream, registry) {
+            eprintln!("serve: connection error: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn serve_unix(path: &str, registry: &Registry) -> Result<(), SignerError> {
+    // A stale socket file from a previous run would otherwise make bind fail.
+    let _ = std::fs::remove_file(path);
+
+    let listener = UnixListener::bind(path)?;
+    eprintln!("serve: listening on unix socket {}", path);
+
+    for stream in listener.incoming() {
+        let stream: UnixStream = stream?;
+        if let Err(e) = handle_connection(stream, registry) {
+            eprintln!("serve: connection error: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn serve_unix(_path: &str, _registry: &Registry) -> Result<(), SignerError> {
+    Err(SignerError::IoError(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "Unix domain sockets are only supported on unix targets",
+    )))
+}
+
+/// Read one HTTP/1.1 request off `stream`, dispatch it, and write the response.
+///
+/// Authorization is checked - and the body-size cap enforced - before the
+/// body is allocated or read, so an unauthenticated client can't force a
+/// large allocation or a slow blocking read with a spoofed `Content-Length`
+/// and no bearer token.
+fn handle_connection<S: Read + Write>(mut stream: S, registry: &Registry) -> std::io::Result<()> {
+    let mut reader = BufReader::new(&mut stream);
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line)? == 0 {
+        return Ok(());
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("").to_string();
+    let path = parts.next().unwrap_or("").to_string();
+
+    let mut content_length = 0usize;
+    let mut authorized = false;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.parse().unwrap_or(0),
+                "authorization" => {
+                    authorized = value
+                        .strip_prefix("Bearer ")
+                        .map(|presented| registry.token.ct_eq(presented.as_bytes()))
+                        .unwrap_or(false)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if !authorized {
+        drop(reader);
+        return write_response(&mut stream, 401, &Output::error("missing or invalid bearer token"));
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        drop(reader);
+        return write_response(&mut stream, 400, &Output::error("request body too large"));
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    drop(reader);
+
+    let (status, output) = match (method.as_str(), path.as_str()) {
+        ("GET", "/keys") => (200, handle_keys(registry)),
+        ("POST", "/sign") => handle_sign(registry, &body),
+        _ => (404, Output::error("not found")),
+    };
+
+    write_response(&mut stream, status, &output)
+}
+
+fn handle_keys(registry: &Registry) -> Output {
+    let public_keys: Vec<&str> = registry.signers.keys().map(String::as_str).collect();
+    Output::success(serde_json::json!({ "public_keys": public_keys }))
+}
+
+/// Body of a `POST /sign` request.
+#[derive(serde::Deserialize)]
+struct SignRequest {
+    pubkey: String,
+    transaction_b64: String,
+}
+
+fn handle_sign(registry: &Registry, body: &[u8]) -> (u16, Output) {
+    let request: SignRequest = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(e) => return (400, Output::error(&format!("invalid request body: {}", e))),
+    };
+
+    let signer = match registry.signers.get(&request.pubkey) {
+        Some(signer) => signer,
+        None => return (404, Output::error("no loaded key matches pubkey")),
+    };
+
+    let transaction_bytes = match base64::Engine::decode(
+        &base64::engine::general_purpose::STANDARD,
+        &request.transaction_b64,
+    ) {
+        Ok(bytes) => bytes,
+        Err(e) => return (400, Output::error(&format!("invalid base64: {}", e))),
+    };
+
+    match signer
+        .sign(&transaction_bytes)
+        .and_then(|result| Ok(serde_json::to_value(&result)?))
+    {
+        Ok(value) => (200, Output::success(value)),
+        Err(e) => (500, Output::from_signer_error(&e)),
+    }
+}
+
+fn write_response<S: Write>(stream: &mut S, status: u16, output: &Output) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        _ => "Internal Server Error",
+    };
+    let body = serde_json::to_vec(output).unwrap_or_default();
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        body.len()
+    )?;
+    stream.write_all(&body)
+}